@@ -1,3 +1,112 @@
+#[path = "src/data.rs"]
+#[allow(dead_code)]
+mod data;
+
+use data::{
+    stroke_file_kanji, stroke_paths, Character, File, RadicalImages, Stroke, Strokes, VocabHash,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    env, fs,
+    path::PathBuf,
+};
+
+/// Parses `{name}.json` and writes it as bincode to `OUT_DIR/{name}.bin`.
+/// A bad entry fails the build with its path in the file
+fn compile<T: DeserializeOwned + Serialize>(name: &str) -> T {
+    let path = format!("{name}.json");
+    println!("cargo:rerun-if-changed={path}");
+    let contents = fs::read_to_string(&path).unwrap_or_else(|err| panic!("{path}: {err}"));
+    let deserializer = &mut serde_json::Deserializer::from_str(&contents);
+    let data: T = serde_path_to_error::deserialize(deserializer)
+        .unwrap_or_else(|err| panic!("{path}: at {}: {}", err.path(), err.inner()));
+    write(name, &data);
+    data
+}
+
+fn write<T: Serialize>(name: &str, data: &T) {
+    let bytes = bincode::serialize(data).expect("serializable dataset");
+    let out = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR")).join(format!("{name}.bin"));
+    fs::write(&out, bytes).unwrap_or_else(|err| panic!("{}: {err}", out.display()));
+}
+
+/// Collects the KanjiVG files in `strokes/` of the kanji in `kanjis.json` or
+/// taught in `levels.json` into `OUT_DIR/strokes.bin`, the rest of KanjiVG is
+/// left out. The folder isn't in the repo, without it the app is built with
+/// no stroke data
+fn compile_strokes(kanjis: &HashMap<String, Character>, levels: &HashMap<usize, File>) {
+    println!("cargo:rerun-if-changed=strokes");
+    let kanjis: HashSet<&str> = kanjis
+        .keys()
+        .map(String::as_str)
+        .chain(
+            levels
+                .values()
+                .flat_map(|a| &a.kanjis)
+                .map(|a| a.character.as_str()),
+        )
+        .collect();
+    let mut strokes = Strokes::new();
+    let entries = match fs::read_dir("strokes") {
+        Ok(entries) => entries,
+        Err(err) => {
+            println!(
+                "cargo:warning=strokes: {err}, building without stroke order. Copy the `kanji` \
+                 folder of a KanjiVG release (https://kanjivg.tagaini.net) to src-tauri/strokes, \
+                 see the README"
+            );
+            write("strokes", &strokes);
+            return;
+        }
+    };
+    for entry in entries.flatten() {
+        let Some(kanji) = stroke_file_kanji(&entry.file_name().to_string_lossy())
+            .filter(|a| kanjis.contains(a.as_str()))
+        else {
+            continue;
+        };
+        let path = entry.path();
+        let svg =
+            fs::read_to_string(&path).unwrap_or_else(|err| panic!("{}: {err}", path.display()));
+        strokes.insert(
+            kanji,
+            stroke_paths(&svg).into_iter().map(Stroke::new).collect(),
+        );
+    }
+    let missing = kanjis.iter().filter(|a| !strokes.contains_key(**a)).count();
+    if missing > 0 {
+        println!("cargo:warning={missing} kanji have no KanjiVG file in strokes");
+    }
+    write("strokes", &strokes);
+}
+
+/// Collects the artwork in `radicals/` into `OUT_DIR/radical_images.bin`,
+/// the file name without `.svg` is the radical id
+fn compile_radical_images() {
+    println!("cargo:rerun-if-changed=radicals");
+    let mut images = RadicalImages::new();
+    for entry in fs::read_dir("radicals").into_iter().flatten().flatten() {
+        let path = entry.path();
+        let Some(id) = path
+            .file_name()
+            .and_then(|a| a.to_str())
+            .and_then(|a| a.strip_suffix(".svg"))
+        else {
+            continue;
+        };
+        let svg =
+            fs::read_to_string(&path).unwrap_or_else(|err| panic!("{}: {err}", path.display()));
+        images.insert(id.to_string(), svg.trim().to_string());
+    }
+    write("radical_images", &images);
+}
+
 fn main() {
+    let levels = compile::<HashMap<usize, File>>("levels");
+    let kanjis = compile::<HashMap<String, Character>>("kanjis");
+    compile::<HashMap<String, VocabHash>>("vocabulary");
+    compile_strokes(&kanjis, &levels);
+    compile_radical_images();
     tauri_build::build()
 }
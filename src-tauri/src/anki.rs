@@ -0,0 +1,444 @@
+//! Writes levels as an Anki package, a zip with a SQLite collection inside
+use rusqlite::{params, Connection};
+use serde_json::{json, Value};
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, Write},
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::data::{radical_id, Character, File, RadicalImages, VocabHash};
+
+const DAY: i64 = 60 * 60 * 24;
+const ROOT_DECK: &str = "Nihon";
+const RADICAL_MODEL: i64 = 1_711_800_000_001;
+const KANJI_MODEL: i64 = 1_711_800_000_002;
+const VOCAB_MODEL: i64 = 1_711_800_000_003;
+const CSS: &str = ".card { font-family: sans-serif; font-size: 24px; text-align: center; }
+.character { font-size: 72px; }
+.character img { width: 72px; height: 72px; }";
+
+const SCHEMA: &str = "
+CREATE TABLE col (
+    id integer primary key, crt integer not null, mod integer not null,
+    scm integer not null, ver integer not null, dty integer not null,
+    usn integer not null, ls integer not null, conf text not null,
+    models text not null, decks text not null, dconf text not null, tags text not null
+);
+CREATE TABLE notes (
+    id integer primary key, guid text not null, mid integer not null,
+    mod integer not null, usn integer not null, tags text not null,
+    flds text not null, sfld integer not null, csum integer not null,
+    flags integer not null, data text not null
+);
+CREATE TABLE cards (
+    id integer primary key, nid integer not null, did integer not null,
+    ord integer not null, mod integer not null, usn integer not null,
+    type integer not null, queue integer not null, due integer not null,
+    ivl integer not null, factor integer not null, reps integer not null,
+    lapses integer not null, left integer not null, odue integer not null,
+    odid integer not null, flags integer not null, data text not null
+);
+CREATE TABLE revlog (
+    id integer primary key, cid integer not null, usn integer not null,
+    ease integer not null, ivl integer not null, lastIvl integer not null,
+    factor integer not null, time integer not null, type integer not null
+);
+CREATE TABLE graves (usn integer not null, oid integer not null, type integer not null);
+CREATE INDEX ix_notes_usn on notes (usn);
+CREATE INDEX ix_cards_usn on cards (usn);
+CREATE INDEX ix_revlog_usn on revlog (usn);
+CREATE INDEX ix_cards_nid on cards (nid);
+CREATE INDEX ix_cards_sched on cards (did, queue, due);
+CREATE INDEX ix_revlog_cid on revlog (cid);
+CREATE INDEX ix_notes_csum on notes (csum);
+";
+
+fn to_io(err: impl std::error::Error + Send + Sync + 'static) -> io::Error {
+    io::Error::other(err)
+}
+
+fn clean(value: &str) -> &str {
+    value.trim_start_matches(['^', '!'])
+}
+
+fn join(values: &[String]) -> String {
+    values
+        .iter()
+        .map(|a| clean(a))
+        .collect::<Vec<&str>>()
+        .join(", ")
+}
+
+/// First 8 hex digits of the sha1 of the sort field, used by Anki to find duplicates
+fn checksum(field: &str) -> i64 {
+    let digest = sha1_smol::Sha1::from(field).digest().bytes();
+    u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]]) as i64
+}
+
+fn model(id: i64, name: &str, fields: &[&str], front: &str, back: &str, now: i64) -> Value {
+    json!({
+        "id": id,
+        "name": name,
+        "type": 0,
+        "mod": now,
+        "usn": -1,
+        "sortf": 0,
+        "did": 1,
+        "tmpls": [{
+            "name": "Recognition",
+            "ord": 0,
+            "qfmt": front,
+            "afmt": back,
+            "did": null,
+            "bqfmt": "",
+            "bafmt": "",
+        }],
+        "flds": fields.iter().enumerate().map(|(ord, name)| json!({
+            "name": name,
+            "ord": ord,
+            "sticky": false,
+            "rtl": false,
+            "font": "Arial",
+            "size": 20,
+            "media": [],
+        })).collect::<Vec<Value>>(),
+        "css": CSS,
+        "latexPre": "\\documentclass[12pt]{article}\n\\special{papersize=3in,5in}\n\\usepackage[utf8]{inputenc}\n\\usepackage{amssymb,amsmath}\n\\pagestyle{empty}\n\\setlength{\\parindent}{0in}\n\\begin{document}\n",
+        "latexPost": "\\end{document}",
+        "tags": [],
+        "vers": [],
+        "req": [[0, "any", [0]]],
+    })
+}
+
+fn deck(id: i64, name: &str, now: i64) -> Value {
+    json!({
+        "id": id,
+        "name": name,
+        "mod": now,
+        "usn": -1,
+        "lrnToday": [0, 0],
+        "revToday": [0, 0],
+        "newToday": [0, 0],
+        "timeToday": [0, 0],
+        "collapsed": false,
+        "desc": "",
+        "dyn": 0,
+        "conf": 1,
+        "extendNew": 10,
+        "extendRev": 50,
+    })
+}
+
+fn deck_config(now: i64) -> Value {
+    json!({
+        "1": {
+            "id": 1,
+            "name": "Default",
+            "mod": now,
+            "usn": -1,
+            "maxTaken": 60,
+            "autoplay": true,
+            "timer": 0,
+            "replayq": true,
+            "dyn": false,
+            "new": {
+                "delays": [1, 10],
+                "ints": [1, 4, 7],
+                "initialFactor": 2500,
+                "order": 1,
+                "perDay": 20,
+                "bury": true,
+                "separate": true,
+            },
+            "rev": {
+                "perDay": 100,
+                "ease4": 1.3,
+                "fuzz": 0.05,
+                "minSpace": 1,
+                "ivlFct": 1,
+                "maxIvl": 36500,
+                "bury": true,
+                "hardFactor": 1.2,
+            },
+            "lapse": {
+                "delays": [10],
+                "mult": 0,
+                "minInt": 1,
+                "leechFails": 8,
+                "leechAction": 0,
+            },
+        }
+    })
+}
+
+/// Scheduling of a card, taken from the SRS state saved for the item
+struct Schedule {
+    r#type: i64,
+    queue: i64,
+    due: i64,
+    ivl: i64,
+    factor: i64,
+}
+
+fn schedule(state: Option<&Value>, position: i64, collection_start: i64) -> Schedule {
+    let interval = state.and_then(|a| a["interval"].as_i64()).unwrap_or(0);
+    match state {
+        Some(state) if interval > 0 => Schedule {
+            r#type: 2,
+            queue: 2,
+            due: ((state["due"].as_i64().unwrap_or(0) - collection_start) / DAY).max(0),
+            ivl: interval,
+            factor: state["ease"].as_i64().unwrap_or(2500),
+        },
+        _ => Schedule {
+            r#type: 0,
+            queue: 0,
+            due: position,
+            ivl: 0,
+            factor: 0,
+        },
+    }
+}
+
+/// The state saved for an item in `viewed.levels`
+fn item_state<'a>(
+    viewed: Option<&'a Value>,
+    level: usize,
+    kind: &str,
+    id: &str,
+) -> Option<&'a Value> {
+    viewed?["levels"][level - 1][kind]
+        .as_array()?
+        .iter()
+        .find(|a| a[0] == id)
+        .map(|a| &a[1])
+}
+
+struct Collection<'a> {
+    connection: &'a Connection,
+    now: i64,
+    next_id: i64,
+    position: i64,
+}
+
+impl Collection<'_> {
+    fn add(
+        &mut self,
+        model: i64,
+        deck: i64,
+        fields: &[String],
+        tags: &str,
+        state: Option<&Value>,
+    ) -> rusqlite::Result<()> {
+        let note = self.next_id;
+        let card = self.next_id + 1;
+        self.next_id += 2;
+        self.position += 1;
+        let guid = format!("nihon-{model}-{}", fields[0]);
+        let sort_field = fields[0].clone();
+        self.connection.execute(
+            "INSERT INTO notes VALUES (?1, ?2, ?3, ?4, -1, ?5, ?6, ?7, ?8, 0, '')",
+            params![
+                note,
+                guid,
+                model,
+                self.now,
+                tags,
+                fields.join("\x1f"),
+                sort_field,
+                checksum(&sort_field),
+            ],
+        )?;
+        let schedule = schedule(state, self.position, self.now - self.now % DAY);
+        self.connection.execute(
+            "INSERT INTO cards VALUES (?1, ?2, ?3, 0, ?4, -1, ?5, ?6, ?7, ?8, ?9, 0, 0, 0, 0, 0, 0, '')",
+            params![
+                card,
+                note,
+                deck,
+                self.now,
+                schedule.r#type,
+                schedule.queue,
+                schedule.due,
+                schedule.ivl,
+                schedule.factor,
+            ],
+        )?;
+        Ok(())
+    }
+}
+
+/// `viewed` is the saved progress, when given the cards keep our intervals.
+/// Radicals drawn as an image take their art along as media, the ones
+/// without art are left out since the card would only show the answer
+pub fn write_apkg(
+    path: &Path,
+    levels: &[(usize, &File)],
+    kanjis: &HashMap<String, Character>,
+    vocabs: &HashMap<String, VocabHash>,
+    images: &RadicalImages,
+    viewed: Option<&Value>,
+) -> io::Result<()> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(to_io)?
+        .as_secs() as i64;
+    let database = path.with_extension("anki2.tmp");
+    if database.exists() {
+        fs::remove_file(&database)?;
+    }
+    let connection = Connection::open(&database).map_err(to_io)?;
+    connection.execute_batch(SCHEMA).map_err(to_io)?;
+
+    let mut decks = json!({ "1": deck(1, "Default", now) });
+    let root_id = now * 1000;
+    decks[root_id.to_string()] = deck(root_id, ROOT_DECK, now);
+    let mut collection = Collection {
+        connection: &connection,
+        now,
+        next_id: now * 1000,
+        position: 0,
+    };
+    // (file name, svg) in the order they go in the zip
+    let mut media: Vec<(String, &str)> = Vec::new();
+    for (level, file) in levels {
+        let deck_id = root_id + *level as i64;
+        decks[deck_id.to_string()] = deck(deck_id, &format!("{ROOT_DECK}::Level {level}"), now);
+        let tags = format!(" level{level} ");
+        for radical in &file.radicals {
+            let character = match radical.character.as_str() {
+                "image" => {
+                    let id = radical_id(&radical.meaning);
+                    let Some(svg) = images.get(&id) else {
+                        continue;
+                    };
+                    let name = format!("nihon-radical-{id}.svg");
+                    if !media.iter().any(|a| a.0 == name) {
+                        media.push((name.clone(), svg.as_str()));
+                    }
+                    format!("<img src=\"{name}\">")
+                }
+                character => character.to_string(),
+            };
+            let state = item_state(viewed, *level, "radicals", &radical.meaning);
+            collection
+                .add(
+                    RADICAL_MODEL,
+                    deck_id,
+                    &[radical.meaning.clone(), character, level.to_string()],
+                    &tags,
+                    state,
+                )
+                .map_err(to_io)?;
+        }
+        for kanji in &file.kanjis {
+            let info = kanjis.get(&kanji.character);
+            let fields = [
+                kanji.character.clone(),
+                join(&kanji.meanings),
+                join(&kanji.readings_on),
+                join(&kanji.readings_kun),
+                info.map(|a| join(&a.wk_radicals)).unwrap_or_default(),
+                level.to_string(),
+            ];
+            let state = item_state(viewed, *level, "kanjis", &kanji.character);
+            collection
+                .add(KANJI_MODEL, deck_id, &fields, &tags, state)
+                .map_err(to_io)?;
+        }
+        for vocab in &file.vocabs {
+            let info = vocabs.get(&vocab.character);
+            let fields = [
+                vocab.character.clone(),
+                info.map(|a| a.primary_meaning.clone())
+                    .unwrap_or(vocab.meaning.clone()),
+                info.map(|a| a.readings.join(", "))
+                    .unwrap_or(vocab.reading.clone()),
+                info.map(|a| a.another_form.join(", ")).unwrap_or_default(),
+                level.to_string(),
+            ];
+            let state = item_state(viewed, *level, "vocabs", &vocab.character);
+            collection
+                .add(VOCAB_MODEL, deck_id, &fields, &tags, state)
+                .map_err(to_io)?;
+        }
+    }
+
+    let models = json!({
+        RADICAL_MODEL.to_string(): model(
+            RADICAL_MODEL,
+            "Nihon Radical",
+            &["Meaning", "Radical", "Level"],
+            "<div class=\"character\">{{Radical}}</div>",
+            "{{FrontSide}}<hr id=answer>{{Meaning}}",
+            now,
+        ),
+        KANJI_MODEL.to_string(): model(
+            KANJI_MODEL,
+            "Nihon Kanji",
+            &["Kanji", "Meanings", "Onyomi", "Kunyomi", "Radicals", "Level"],
+            "<div class=\"character\">{{Kanji}}</div>",
+            "{{FrontSide}}<hr id=answer>{{Meanings}}<br>{{Onyomi}}<br>{{Kunyomi}}<br><small>{{Radicals}}</small>",
+            now,
+        ),
+        VOCAB_MODEL.to_string(): model(
+            VOCAB_MODEL,
+            "Nihon Vocabulary",
+            &["Word", "Meaning", "Reading", "Other forms", "Level"],
+            "<div class=\"character\">{{Word}}</div>",
+            "{{FrontSide}}<hr id=answer>{{Meaning}}<br>{{Reading}}<br><small>{{Other forms}}</small>",
+            now,
+        ),
+    });
+    let conf = json!({
+        "nextPos": collection.position + 1,
+        "estTimes": true,
+        "activeDecks": [1],
+        "sortType": "noteFld",
+        "timeLim": 0,
+        "sortBackwards": false,
+        "addToCur": true,
+        "curDeck": 1,
+        "newBust": true,
+        "dueCounts": true,
+        "curModel": null,
+        "collapseTime": 1200,
+    });
+    connection
+        .execute(
+            "INSERT INTO col VALUES (1, ?1, ?2, ?3, 11, 0, 0, 0, ?4, ?5, ?6, ?7, '{}')",
+            params![
+                now - now % DAY,
+                now * 1000,
+                now * 1000,
+                conf.to_string(),
+                models.to_string(),
+                decks.to_string(),
+                deck_config(now).to_string(),
+            ],
+        )
+        .map_err(to_io)?;
+    connection.close().map_err(|(_, err)| to_io(err))?;
+
+    let collection = fs::read(&database)?;
+    fs::remove_file(&database)?;
+    let mut zip = zip::ZipWriter::new(fs::File::create(path)?);
+    let options = zip::write::SimpleFileOptions::default();
+    zip.start_file("collection.anki2", options).map_err(to_io)?;
+    zip.write_all(&collection)?;
+    // Anki wants the media numbered, with a map back to the real names
+    let mut names = serde_json::Map::new();
+    for (index, (name, svg)) in media.iter().enumerate() {
+        zip.start_file(index.to_string(), options).map_err(to_io)?;
+        zip.write_all(svg.as_bytes())?;
+        names.insert(index.to_string(), Value::from(name.as_str()));
+    }
+    zip.start_file("media", options).map_err(to_io)?;
+    zip.write_all(Value::Object(names).to_string().as_bytes())?;
+    zip.finish().map_err(to_io)?;
+    Ok(())
+}
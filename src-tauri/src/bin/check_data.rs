@@ -0,0 +1,57 @@
+//! Reports every inconsistency between the bundled datasets.
+//! Run with `cargo run --bin check_data [directory with the json files]`
+use nihon_lib::data::{check, Character, File, RadicalImages, VocabHash};
+use serde::de::DeserializeOwned;
+use std::{collections::HashMap, env, fs, path::Path, process::ExitCode};
+
+fn read<T: DeserializeOwned>(dir: &Path, name: &str) -> Result<T, String> {
+    let path = dir.join(name);
+    let contents = fs::read_to_string(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+    serde_json::from_str(&contents).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+/// Only the names matter to the check
+fn read_images(dir: &Path) -> RadicalImages {
+    fs::read_dir(dir.join("radicals"))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            name.strip_suffix(".svg")
+                .map(|id| (id.to_string(), String::new()))
+        })
+        .collect()
+}
+
+fn main() -> ExitCode {
+    let dir = env::args()
+        .nth(1)
+        .unwrap_or(env!("CARGO_MANIFEST_DIR").to_string());
+    let dir = Path::new(&dir);
+    let datasets = (|| {
+        Ok::<_, String>((
+            read::<HashMap<usize, File>>(dir, "levels.json")?,
+            read::<HashMap<String, Character>>(dir, "kanjis.json")?,
+            read::<HashMap<String, VocabHash>>(dir, "vocabulary.json")?,
+        ))
+    })();
+    let (levels, kanjis, vocabs) = match datasets {
+        Ok(a) => a,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let errors = check(&levels, &kanjis, &vocabs, &read_images(dir));
+    for error in &errors {
+        println!("{error}");
+    }
+    if errors.is_empty() {
+        println!("everything matches");
+        ExitCode::SUCCESS
+    } else {
+        println!("{} problems found", errors.len());
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,363 @@
+//! Types of the bundled datasets. The build script shares this file to
+//! check the json files and turn them into bincode
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct VocabHash {
+    pub meanings: Vec<String>,
+    pub wk_level: Option<u32>,
+    pub readings: Vec<String>,
+    pub primary_reading: String,
+    pub primary_meaning: String,
+    pub another_form: Vec<String>,
+}
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Character {
+    pub strokes: u32,
+    pub freq: Option<u32>,
+    pub wk_meanings: Vec<String>,
+    pub wk_readings_on: Vec<String>,
+    pub wk_readings_kun: Vec<String>,
+    pub wk_radicals: Vec<String>,
+    pub wk_level: Option<u32>,
+}
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Kanji {
+    pub character: String,
+    pub meanings: Vec<String>,
+    pub readings_kun: Vec<String>,
+    pub readings_on: Vec<String>,
+}
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Vocab {
+    pub character: String,
+    pub meaning: String,
+    pub reading: String,
+}
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Radical {
+    pub character: String,
+    pub meaning: String,
+}
+#[derive(Serialize, Deserialize, Clone)]
+pub struct File {
+    pub kanjis: Vec<Kanji>,
+    pub vocabs: Vec<Vocab>,
+    pub radicals: Vec<Radical>,
+}
+
+pub type Point = (f64, f64);
+
+/// One stroke of a kanji in the 109x109 box of KanjiVG. The points are worked
+/// out from the path here so nothing else has to read svg paths
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Stroke {
+    pub path: String,
+    pub points: Vec<Point>,
+}
+impl Stroke {
+    pub fn new(path: String) -> Self {
+        let points = path_points(&path);
+        Self { path, points }
+    }
+}
+
+/// Strokes of each kanji in writing order
+pub type Strokes = HashMap<String, Vec<Stroke>>;
+
+/// The `d` of every `<path>` of a KanjiVG svg, they come in stroke order
+pub fn stroke_paths(svg: &str) -> Vec<String> {
+    svg.split("<path")
+        .skip(1)
+        .filter_map(|tag| {
+            let tag = &tag[..tag.find('>')?];
+            let start = tag.find(" d=\"")? + 4;
+            let end = start + tag[start..].find('"')?;
+            Some(tag[start..end].to_string())
+        })
+        .collect()
+}
+
+enum PathToken {
+    Command(char),
+    Number(f64),
+}
+
+/// Splits a path in commands and numbers, numbers can be glued like `1.5-2.5.5`
+fn path_tokens(path: &str) -> Vec<PathToken> {
+    let chars: Vec<char> = path.chars().collect();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+    while index < chars.len() {
+        let char = chars[index];
+        if char.is_ascii_alphabetic() {
+            tokens.push(PathToken::Command(char));
+            index += 1;
+            continue;
+        }
+        if !(char.is_ascii_digit() || char == '-' || char == '+' || char == '.') {
+            index += 1;
+            continue;
+        }
+        let start = index;
+        let mut seen_dot = char == '.';
+        index += 1;
+        while let Some(&next) = chars.get(index) {
+            match next {
+                '0'..='9' => {}
+                '.' if !seen_dot => seen_dot = true,
+                _ => break,
+            }
+            index += 1;
+        }
+        let number: String = chars[start..index].iter().collect();
+        if let Ok(number) = number.parse::<f64>() {
+            tokens.push(PathToken::Number(number));
+        }
+    }
+    tokens
+}
+
+fn cubic(from: Point, a: Point, b: Point, to: Point, t: f64) -> Point {
+    let u = 1.0 - t;
+    let point = |p0: f64, p1: f64, p2: f64, p3: f64| {
+        u * u * u * p0 + 3.0 * u * u * t * p1 + 3.0 * u * t * t * p2 + t * t * t * p3
+    };
+    (point(from.0, a.0, b.0, to.0), point(from.1, a.1, b.1, to.1))
+}
+
+/// Points along a path, curves are cut in a few straight pieces.
+/// Only what KanjiVG uses is understood: M, L, H, V, C, S and Z
+pub fn path_points(path: &str) -> Vec<Point> {
+    const CURVE_STEPS: usize = 8;
+    let tokens = path_tokens(path);
+    let mut points = Vec::new();
+    let mut current = (0.0, 0.0);
+    let mut start = (0.0, 0.0);
+    // Second control point of the last curve, S mirrors it
+    let mut control = None::<Point>;
+    let mut command = 'M';
+    let mut index = 0;
+    while index < tokens.len() {
+        if let PathToken::Command(char) = tokens[index] {
+            command = char;
+            index += 1;
+            if command.eq_ignore_ascii_case(&'z') {
+                current = start;
+                points.push(current);
+                control = None;
+            }
+            continue;
+        }
+        let needed = match command.to_ascii_uppercase() {
+            'M' | 'L' => 2,
+            'H' | 'V' => 1,
+            'S' => 4,
+            'C' => 6,
+            _ => {
+                index += 1;
+                continue;
+            }
+        };
+        let values: Vec<f64> = tokens[index..]
+            .iter()
+            .take(needed)
+            .map_while(|a| match a {
+                PathToken::Number(a) => Some(*a),
+                PathToken::Command(_) => None,
+            })
+            .collect();
+        if values.len() < needed {
+            break;
+        }
+        index += needed;
+        let relative = command.is_ascii_lowercase();
+        let point = |x: f64, y: f64| match relative {
+            true => (current.0 + x, current.1 + y),
+            false => (x, y),
+        };
+        match command.to_ascii_uppercase() {
+            'M' => {
+                current = point(values[0], values[1]);
+                start = current;
+                points.push(current);
+                control = None;
+                // More pairs after a move are lines
+                command = if relative { 'l' } else { 'L' };
+            }
+            'L' => {
+                current = point(values[0], values[1]);
+                points.push(current);
+                control = None;
+            }
+            'H' => {
+                current.0 = if relative {
+                    current.0 + values[0]
+                } else {
+                    values[0]
+                };
+                points.push(current);
+                control = None;
+            }
+            'V' => {
+                current.1 = if relative {
+                    current.1 + values[0]
+                } else {
+                    values[0]
+                };
+                points.push(current);
+                control = None;
+            }
+            _ => {
+                let (a, b, to) = match values.len() {
+                    6 => (
+                        point(values[0], values[1]),
+                        point(values[2], values[3]),
+                        point(values[4], values[5]),
+                    ),
+                    _ => (
+                        control
+                            .map(|c| (2.0 * current.0 - c.0, 2.0 * current.1 - c.1))
+                            .unwrap_or(current),
+                        point(values[0], values[1]),
+                        point(values[2], values[3]),
+                    ),
+                };
+                for step in 1..=CURVE_STEPS {
+                    points.push(cubic(current, a, b, to, step as f64 / CURVE_STEPS as f64));
+                }
+                current = to;
+                control = Some(b);
+            }
+        }
+    }
+    points
+}
+
+/// The kanji a KanjiVG file is named after, `05e73.svg` is 干.
+/// Variants like `05e73-Kaisho.svg` are None
+pub fn stroke_file_kanji(name: &str) -> Option<String> {
+    let code = name.strip_suffix(".svg")?;
+    let code = u32::from_str_radix(code, 16).ok()?;
+    char::from_u32(code).map(String::from)
+}
+
+/// Artwork of the radicals that have no character, keyed by `radical_id`
+pub type RadicalImages = HashMap<String, String>;
+
+/// Name of the artwork file of a radical, `Death Star` is `death-star`
+pub fn radical_id(meaning: &str) -> String {
+    meaning.trim().to_lowercase().replace(' ', "-")
+}
+
+/// Radicals shown as an image that have no artwork, with their path in `levels.json`
+pub fn missing_images(levels: &HashMap<usize, File>, images: &RadicalImages) -> Vec<String> {
+    let mut sorted: Vec<_> = levels.iter().collect();
+    sorted.sort_by_key(|a| a.0);
+    let mut missing = Vec::new();
+    for (level, file) in sorted {
+        for (index, radical) in file.radicals.iter().enumerate() {
+            let id = radical_id(&radical.meaning);
+            if radical.character == "image" && !images.contains_key(&id) {
+                missing.push(format!(
+                    "levels.json: {level}.radicals[{index}] {}: no artwork in radicals/{id}.svg",
+                    radical.meaning
+                ));
+            }
+        }
+    }
+    missing
+}
+
+/// Reads a dataset written by the build script, it was already checked there
+pub fn load<T: DeserializeOwned>(bytes: &[u8]) -> T {
+    bincode::deserialize(bytes).expect("dataset compiled by build.rs")
+}
+
+/// Every place where the three datasets disagree, with the path of the entry
+pub fn check(
+    levels: &HashMap<usize, File>,
+    kanjis: &HashMap<String, Character>,
+    vocabs: &HashMap<String, VocabHash>,
+    images: &RadicalImages,
+) -> Vec<String> {
+    let mut errors = Vec::new();
+    let mut sorted: Vec<_> = levels.iter().collect();
+    sorted.sort_by_key(|a| a.0);
+    let radicals: HashSet<&String> = levels
+        .values()
+        .flat_map(|file| file.radicals.iter().map(|a| &a.meaning))
+        .collect();
+    let mut listed = HashMap::new();
+
+    for (level, file) in &sorted {
+        for (index, kanji) in file.kanjis.iter().enumerate() {
+            let path = format!("levels.json: {level}.kanjis[{index}] {}", kanji.character);
+            if kanji.meanings.is_empty() {
+                errors.push(format!("{path}: has no meanings"));
+            }
+            if kanji.readings_on.is_empty() && kanji.readings_kun.is_empty() {
+                errors.push(format!("{path}: has no readings"));
+            }
+            match kanjis.get(&kanji.character) {
+                None => errors.push(format!("{path}: missing from kanjis.json")),
+                Some(info) if info.wk_level != Some(**level as u32) => errors.push(format!(
+                    "{path}: kanjis.json says level {}",
+                    info.wk_level
+                        .map(|a| a.to_string())
+                        .unwrap_or("none".into())
+                )),
+                Some(_) => {}
+            }
+            if let Some(other) = listed.insert(("kanji", &kanji.character), level) {
+                errors.push(format!("{path}: also listed in level {other}"));
+            }
+        }
+        for (index, vocab) in file.vocabs.iter().enumerate() {
+            let path = format!("levels.json: {level}.vocabs[{index}] {}", vocab.character);
+            if vocab.meaning.is_empty() {
+                errors.push(format!("{path}: has no meaning"));
+            }
+            if vocab.reading.is_empty() {
+                errors.push(format!("{path}: has no reading"));
+            }
+            match vocabs.get(&vocab.character) {
+                None => errors.push(format!("{path}: missing from vocabulary.json")),
+                Some(info) if info.wk_level != Some(**level as u32) => errors.push(format!(
+                    "{path}: vocabulary.json says level {}",
+                    info.wk_level
+                        .map(|a| a.to_string())
+                        .unwrap_or("none".into())
+                )),
+                Some(_) => {}
+            }
+            if let Some(other) = listed.insert(("vocab", &vocab.character), level) {
+                errors.push(format!("{path}: also listed in level {other}"));
+            }
+        }
+        for (index, radical) in file.radicals.iter().enumerate() {
+            let path = format!("levels.json: {level}.radicals[{index}] {}", radical.meaning);
+            if radical.character.is_empty() {
+                errors.push(format!("{path}: has no character"));
+            }
+            if let Some(other) = listed.insert(("radical", &radical.meaning), level) {
+                errors.push(format!("{path}: also listed in level {other}"));
+            }
+        }
+    }
+
+    let mut characters: Vec<_> = kanjis.iter().collect();
+    characters.sort_by_key(|a| (a.1.wk_level, a.0));
+    for (character, info) in characters {
+        for (index, radical) in info.wk_radicals.iter().enumerate() {
+            if !radicals.contains(radical) {
+                errors.push(format!(
+                    "kanjis.json: {character}.wk_radicals[{index}] {radical}: not a radical in levels.json"
+                ));
+            }
+        }
+    }
+    errors.extend(missing_images(levels, images));
+    errors
+}
@@ -0,0 +1,213 @@
+//! Compares drawn strokes with the KanjiVG ones, to grade the writing review
+//! and to recognize a kanji drawn on the search page. For recognizing, the
+//! drawing and every kanji are scaled to the same box first so the size and
+//! position of the drawing don't matter
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    data::{Character, Point, Stroke, Strokes},
+    search::{kanji_result, SearchResult},
+};
+
+/// Side of the box the KanjiVG paths are drawn in
+const STROKE_BOX: f64 = 109.0;
+/// Points every stroke is cut in before recognizing
+const SAMPLES: usize = 12;
+/// Points every stroke is cut in before grading, one stroke is looked at closer
+const GRADING_SAMPLES: usize = 16;
+/// How far a graded stroke can be from the reference on average, in the 109 box
+const MAX_DISTANCE: f64 = 16.0;
+/// Kanji with more or less strokes than this are not considered
+const MAX_STROKE_DIFFERENCE: usize = 2;
+/// Cost of every stroke missing or drawn in excess
+const MISSING_STROKE: f64 = 30.0;
+const MAX_RESULTS: usize = 10;
+
+/// Strokes of every kanji in `kanjis.json` with KanjiVG data, already scaled and sampled
+pub struct Templates(HashMap<String, Vec<Vec<Point>>>);
+
+impl Templates {
+    pub fn new(strokes: &Strokes, kanjis: &HashMap<String, Character>) -> Self {
+        Self(
+            strokes
+                .iter()
+                .filter(|(kanji, _)| kanjis.contains_key(*kanji))
+                .filter_map(|(kanji, strokes)| {
+                    // Paths that parsed to nothing would match any stroke
+                    let points: Vec<Vec<Point>> = strokes
+                        .iter()
+                        .filter(|a| !a.points.is_empty())
+                        .map(|a| a.points.clone())
+                        .collect();
+                    (!points.is_empty()).then(|| (kanji.clone(), prepare(points)))
+                })
+                .collect(),
+        )
+    }
+}
+
+fn distance(a: Point, b: Point) -> f64 {
+    ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()
+}
+
+/// `samples` points spread evenly along the line
+fn resample(points: &[Point], samples: usize) -> Vec<Point> {
+    let (Some(&first), Some(&last)) = (points.first(), points.last()) else {
+        return Vec::new();
+    };
+    let total: f64 = points.windows(2).map(|a| distance(a[0], a[1])).sum();
+    if total == 0.0 {
+        return vec![first; samples];
+    }
+    let step = total / (samples - 1) as f64;
+    let mut result = vec![first];
+    let mut walked = 0.0;
+    let mut target = step;
+    for pair in points.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        let segment = distance(a, b);
+        while segment > 0.0 && target <= walked + segment && result.len() < samples - 1 {
+            let t = (target - walked) / segment;
+            result.push((a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t));
+            target += step;
+        }
+        walked += segment;
+    }
+    while result.len() < samples {
+        result.push(last);
+    }
+    result
+}
+
+/// Scales the strokes together to fill the box, keeping their proportions, and samples them
+fn prepare(strokes: Vec<Vec<Point>>) -> Vec<Vec<Point>> {
+    let points = || strokes.iter().flatten();
+    let min_x = points().map(|a| a.0).fold(f64::INFINITY, f64::min);
+    let min_y = points().map(|a| a.1).fold(f64::INFINITY, f64::min);
+    let width = points().map(|a| a.0).fold(f64::NEG_INFINITY, f64::max) - min_x;
+    let height = points().map(|a| a.1).fold(f64::NEG_INFINITY, f64::max) - min_y;
+    let size = width.max(height);
+    // A single dot has no size to scale
+    let scale = if size > 1.0 { STROKE_BOX / size } else { 1.0 };
+    let offset = (
+        (STROKE_BOX - width * scale) / 2.0,
+        (STROKE_BOX - height * scale) / 2.0,
+    );
+    strokes
+        .iter()
+        .map(|stroke| {
+            let scaled: Vec<Point> = stroke
+                .iter()
+                .map(|a| {
+                    (
+                        (a.0 - min_x) * scale + offset.0,
+                        (a.1 - min_y) * scale + offset.1,
+                    )
+                })
+                .collect();
+            resample(&scaled, SAMPLES)
+        })
+        .collect()
+}
+
+/// Average distance between the matching points of two resampled strokes
+fn difference(a: &[Point], b: &[Point]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| distance(*a, *b)).sum::<f64>() / a.len().max(1) as f64
+}
+
+/// How far a drawing is from a kanji, lower is closer. Strokes are matched to
+/// the closest one left in any order and direction since that is what people
+/// who don't know the kanji get wrong
+fn cost(drawing: &[Vec<Point>], template: &[Vec<Point>]) -> f64 {
+    let mut left: Vec<&Vec<Point>> = template.iter().collect();
+    let mut total = 0.0;
+    for stroke in drawing {
+        let reversed: Vec<Point> = stroke.iter().rev().copied().collect();
+        let best = left
+            .iter()
+            .enumerate()
+            .map(|(index, other)| {
+                let difference = difference(stroke, other).min(difference(&reversed, other));
+                (index, difference)
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+        match best {
+            Some((index, difference)) => {
+                total += difference;
+                left.remove(index);
+            }
+            None => total += MISSING_STROKE,
+        }
+    }
+    total += left.len() as f64 * MISSING_STROKE;
+    total / drawing.len().max(template.len()) as f64
+}
+
+/// The kanji closest to the drawing, best first
+pub fn recognize(
+    drawing: Vec<Vec<Point>>,
+    templates: &Templates,
+    kanjis: &HashMap<String, Character>,
+) -> Vec<SearchResult> {
+    let drawing: Vec<Vec<Point>> = drawing.into_iter().filter(|a| !a.is_empty()).collect();
+    if drawing.is_empty() {
+        return Vec::new();
+    }
+    let drawing = prepare(drawing);
+    let mut candidates: Vec<(&String, f64)> = templates
+        .0
+        .iter()
+        .filter(|(_, template)| template.len().abs_diff(drawing.len()) <= MAX_STROKE_DIFFERENCE)
+        .map(|(kanji, template)| (kanji, cost(&drawing, template)))
+        .collect();
+    candidates.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(b.0)));
+    candidates
+        .into_iter()
+        .take(MAX_RESULTS)
+        .filter_map(|(kanji, cost)| {
+            let info = kanjis.get(kanji)?;
+            Some(kanji_result(kanji, info, (1.0 / (1.0 + cost)) as f32))
+        })
+        .collect()
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StrokeResult {
+    Good,
+    Backwards,
+    /// Looks like a stroke that comes later, with its index
+    OutOfOrder(usize),
+    Off,
+}
+
+/// Compares a stroke drawn in the writing review with the one expected at
+/// `index`. Nothing is scaled, where the stroke is drawn matters here
+pub fn grade_stroke(drawn: &[Point], strokes: &[Stroke], index: usize) -> StrokeResult {
+    if index >= strokes.len() || strokes[index].points.is_empty() || drawn.is_empty() {
+        return StrokeResult::Off;
+    }
+    let drawn = resample(drawn, GRADING_SAMPLES);
+    let reversed: Vec<Point> = drawn.iter().rev().copied().collect();
+    let matches = |index: usize| {
+        // A path that parsed to nothing looks like nothing
+        if strokes[index].points.is_empty() {
+            return (false, false);
+        }
+        let reference = resample(&strokes[index].points, GRADING_SAMPLES);
+        (
+            difference(&drawn, &reference) <= MAX_DISTANCE,
+            difference(&reversed, &reference) <= MAX_DISTANCE,
+        )
+    };
+    match matches(index) {
+        (true, _) => return StrokeResult::Good,
+        (false, true) => return StrokeResult::Backwards,
+        _ => {}
+    }
+    match (index + 1..strokes.len()).find(|other| matches(*other) != (false, false)) {
+        Some(other) => StrokeResult::OutOfOrder(other),
+        None => StrokeResult::Off,
+    }
+}
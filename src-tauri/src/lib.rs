@@ -1,54 +1,66 @@
+mod anki;
+pub mod data;
+mod handwriting;
+mod packs;
+mod progress;
+mod search;
+mod settings;
+
+use data::{Character, File, Point, RadicalImages, Stroke, Strokes, VocabHash};
+use handwriting::{StrokeResult, Templates};
+use packs::LevelsInfo;
+use progress::{ImportMode, ImportReport, Progress};
+use search::SearchResult;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use tauri::Manager;
+use tauri_plugin_dialog::DialogExt;
 
 #[derive(Serialize, Deserialize, Clone)]
 struct RadicalHash {
     character: String,
     wk_level: Option<u32>,
 }
+/// Kanji built from each radical, keyed by the radical meaning
+struct RadicalKanjis(HashMap<String, Vec<String>>);
 #[derive(Serialize, Deserialize, Clone)]
-struct VocabHash {
-    meanings: Vec<String>,
-    wk_level: Option<u32>,
-    readings: Vec<String>,
-    primary_reading: String,
-    primary_meaning: String,
-    another_form: Vec<String>,
-}
-#[derive(Serialize, Deserialize, Clone)]
-struct Character {
-    strokes: u32,
-    freq: Option<u32>,
-    wk_meanings: Vec<String>,
-    wk_readings_on: Vec<String>,
-    wk_readings_kun: Vec<String>,
-    wk_radicals: Vec<String>,
-    wk_level: Option<u32>,
+struct KanjiSummary {
+    character: String,
+    meaning: String,
+    /// Counting from 0, the level of the levels files it is taught in
+    level: Option<usize>,
 }
+/// Kanji that look alike for being built from the same radicals
 #[derive(Serialize, Deserialize, Clone)]
-pub struct Kanji {
+struct Neighbour {
     character: String,
     meanings: Vec<String>,
-    readings_kun: Vec<String>,
-    readings_on: Vec<String>,
+    readings: Vec<String>,
 }
+/// Artwork of the radicals without a character
+struct RadicalArt(RadicalImages);
+/// Stroke order of the kanji with KanjiVG data bundled
+struct KanjiStrokes(Strokes);
+/// Vocabulary written with each kanji
+struct KanjiVocabs(HashMap<String, Vec<String>>);
 #[derive(Serialize, Deserialize, Clone)]
-pub struct Vocab {
+struct VocabSummary {
     character: String,
-    meaning: String,
     reading: String,
-}
-#[derive(Serialize, Deserialize, Clone)]
-pub struct Radical {
-    character: String,
     meaning: String,
+    /// Counting from 0, the level of the levels files it is taught in
+    level: Option<usize>,
 }
-#[derive(Serialize, Deserialize, Clone)]
-pub struct File {
-    kanjis: Vec<Kanji>,
-    vocabs: Vec<Vocab>,
-    radicals: Vec<Radical>,
+
+/// First level whose file has the item. The levels files don't always agree
+/// with `wk_level`, the progress follows the files
+fn level_of(levels: &HashMap<usize, File>, has: impl Fn(&File) -> bool) -> Option<usize> {
+    levels
+        .iter()
+        .filter(|(_, file)| has(file))
+        .map(|(level, _)| *level)
+        .min()
+        .and_then(|a| a.checked_sub(1))
 }
 
 #[tauri::command]
@@ -56,12 +68,76 @@ fn get_kanjis<'a>(level: usize, levels: tauri::State<HashMap<usize, File>, 'a>)
     levels.get(&level).cloned()
 }
 #[tauri::command]
+fn get_levels_info<'a>(info: tauri::State<LevelsInfo, 'a>) -> LevelsInfo {
+    info.inner().clone()
+}
+#[tauri::command]
+fn get_kanjis_range<'a>(
+    levels: Vec<usize>,
+    all_levels: tauri::State<HashMap<usize, File>, 'a>,
+) -> Vec<(usize, File)> {
+    levels
+        .into_iter()
+        .flat_map(|level| all_levels.get(&level).map(|file| (level, file.clone())))
+        .collect()
+}
+#[tauri::command]
 fn get_kanji_reading<'a>(
     kanji: String,
     all_kanjis: tauri::State<HashMap<String, Character>, 'a>,
 ) -> Option<Character> {
     all_kanjis.get(&kanji).cloned()
 }
+/// Every radical artwork, keyed by the id made from the radical meaning
+#[tauri::command]
+fn get_radical_images<'a>(images: tauri::State<RadicalArt, 'a>) -> RadicalImages {
+    images.0.clone()
+}
+/// Strokes in writing order, None if there is no data for it
+#[tauri::command]
+fn get_kanji_strokes<'a>(
+    kanji: String,
+    strokes: tauri::State<KanjiStrokes, 'a>,
+) -> Option<Vec<Stroke>> {
+    strokes.0.get(&kanji).cloned()
+}
+/// Checks a stroke drawn in the writing review, `index` is the stroke expected
+#[tauri::command]
+fn grade_stroke<'a>(
+    kanji: String,
+    index: usize,
+    drawn: Vec<Point>,
+    strokes: tauri::State<KanjiStrokes, 'a>,
+) -> StrokeResult {
+    match strokes.0.get(&kanji) {
+        Some(strokes) => handwriting::grade_stroke(&drawn, strokes, index),
+        None => StrokeResult::Off,
+    }
+}
+/// False when the app was built without any stroke data
+#[tauri::command]
+fn has_strokes<'a>(strokes: tauri::State<KanjiStrokes, 'a>) -> bool {
+    !strokes.0.is_empty()
+}
+/// Paths of the strokes of every kanji asked that has stroke data
+#[tauri::command]
+fn get_strokes_range<'a>(
+    kanjis: Vec<String>,
+    strokes: tauri::State<KanjiStrokes, 'a>,
+) -> HashMap<String, Vec<String>> {
+    kanjis
+        .into_iter()
+        .flat_map(|kanji| {
+            let paths = strokes
+                .0
+                .get(&kanji)?
+                .iter()
+                .map(|a| a.path.clone())
+                .collect();
+            Some((kanji, paths))
+        })
+        .collect()
+}
 #[tauri::command]
 async fn open_url(url: String) -> bool {
     webbrowser::open(&url).is_ok()
@@ -74,6 +150,102 @@ fn get_radical<'a>(
     all_radicals.get(&meaning).cloned()
 }
 #[tauri::command]
+fn get_radical_kanjis<'a>(
+    meaning: String,
+    radical_kanjis: tauri::State<RadicalKanjis, 'a>,
+    all_kanjis: tauri::State<HashMap<String, Character>, 'a>,
+    all_levels: tauri::State<HashMap<usize, File>, 'a>,
+) -> Vec<KanjiSummary> {
+    let Some(kanjis) = radical_kanjis.0.get(&meaning) else {
+        return Vec::new();
+    };
+    kanjis
+        .iter()
+        .flat_map(|kanji| {
+            all_kanjis.get(kanji).map(|info| KanjiSummary {
+                character: kanji.clone(),
+                meaning: info
+                    .wk_meanings
+                    .first()
+                    .map(|a| a.trim_start_matches(['^', '!']).to_string())
+                    .unwrap_or_default(),
+                level: level_of(&all_levels, |file| {
+                    file.kanjis.iter().any(|a| a.character == *kanji)
+                }),
+            })
+        })
+        .collect()
+}
+/// Up to `NEIGHBOURS` kanji sharing a radical with each one asked, closest level first
+#[tauri::command]
+fn get_radical_neighbours<'a>(
+    kanjis: Vec<String>,
+    radical_kanjis: tauri::State<RadicalKanjis, 'a>,
+    all_kanjis: tauri::State<HashMap<String, Character>, 'a>,
+) -> HashMap<String, Vec<Neighbour>> {
+    const NEIGHBOURS: usize = 6;
+    let mut result = HashMap::new();
+    for kanji in kanjis {
+        let Some(info) = all_kanjis.get(&kanji) else {
+            continue;
+        };
+        let level = info.wk_level.unwrap_or(0) as i64;
+        let mut others: Vec<&String> = info
+            .wk_radicals
+            .iter()
+            .flat_map(|radical| radical_kanjis.0.get(radical))
+            .flatten()
+            .filter(|a| **a != kanji)
+            .collect();
+        others.sort();
+        others.dedup();
+        others.sort_by_key(|a| (all_kanjis[*a].wk_level.unwrap_or(0) as i64 - level).abs());
+        let neighbours = others
+            .into_iter()
+            .take(NEIGHBOURS)
+            .map(|other| {
+                let info = &all_kanjis[other];
+                Neighbour {
+                    character: other.clone(),
+                    meanings: info.wk_meanings.clone(),
+                    readings: info
+                        .wk_readings_on
+                        .iter()
+                        .chain(info.wk_readings_kun.iter())
+                        .cloned()
+                        .collect(),
+                }
+            })
+            .collect();
+        result.insert(kanji, neighbours);
+    }
+    result
+}
+#[tauri::command]
+fn get_kanji_vocabs<'a>(
+    kanji: String,
+    kanji_vocabs: tauri::State<KanjiVocabs, 'a>,
+    all_vocabs: tauri::State<HashMap<String, VocabHash>, 'a>,
+    all_levels: tauri::State<HashMap<usize, File>, 'a>,
+) -> Vec<VocabSummary> {
+    let Some(vocabs) = kanji_vocabs.0.get(&kanji) else {
+        return Vec::new();
+    };
+    vocabs
+        .iter()
+        .flat_map(|vocab| {
+            all_vocabs.get(vocab).map(|info| VocabSummary {
+                character: vocab.clone(),
+                reading: info.primary_reading.clone(),
+                meaning: info.primary_meaning.clone(),
+                level: level_of(&all_levels, |file| {
+                    file.vocabs.iter().any(|a| a.character == *vocab)
+                }),
+            })
+        })
+        .collect()
+}
+#[tauri::command]
 fn get_vocab<'a>(
     vocab: String,
     all_vocabs: tauri::State<HashMap<String, VocabHash>, 'a>,
@@ -94,27 +266,253 @@ fn get_vocab_range<'a>(
 
     (!vec.is_empty()).then(|| vec)
 }
+/// None when there is nothing saved yet. An unreadable file is an error
+/// and gets copied to a backup, the frontend must not save over it
+#[tauri::command]
+fn load_progress(app: tauri::AppHandle) -> Result<Option<Progress>, String> {
+    progress::read_progress(&app).map_err(|err| {
+        eprintln!("failed to read progress: {err}");
+        match progress::backup_progress(&app) {
+            Ok(backup) => format!("{err}\nA copy was kept at {}", backup.display()),
+            Err(backup_err) => {
+                eprintln!("failed to backup progress: {backup_err}");
+                err.to_string()
+            }
+        }
+    })
+}
+#[tauri::command]
+fn save_progress(app: tauri::AppHandle, progress: Progress) -> bool {
+    match progress::write_progress(&app, progress) {
+        Ok(()) => true,
+        Err(err) => {
+            eprintln!("failed to save progress: {err}");
+            false
+        }
+    }
+}
+/// The answer log, answers of packs that aren't loaded are left out
+#[tauri::command]
+fn load_answers(app: tauri::AppHandle) -> Result<Vec<serde_json::Value>, String> {
+    progress::read_answers(&app).map_err(|err| err.to_string())
+}
+/// Adds the answers of a finished review to the log
+#[tauri::command]
+fn append_answers(app: tauri::AppHandle, answers: Vec<serde_json::Value>) -> bool {
+    match progress::append_answers(&app, &answers) {
+        Ok(()) => true,
+        Err(err) => {
+            eprintln!("failed to save answers: {err}");
+            false
+        }
+    }
+}
+/// Writes the saved progress wherever the user picks, false if cancelled
+#[tauri::command]
+async fn export_progress(app: tauri::AppHandle) -> Result<bool, String> {
+    let mut progress = progress::read_stored(&app)
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| String::from("there is no progress saved yet"))?;
+    progress.answers = progress::export_answers(&app).map_err(|err| format!("answers: {err}"))?;
+    let Some(path) = app
+        .dialog()
+        .file()
+        .add_filter("Progress", &["json"])
+        .set_file_name("nihon-progress.json")
+        .blocking_save_file()
+        .and_then(|a| a.into_path().ok())
+    else {
+        return Ok(false);
+    };
+    let contents = serde_json::to_vec_pretty(&progress).map_err(|err| err.to_string())?;
+    progress::write_atomic(&path, &contents).map_err(|err| err.to_string())?;
+    Ok(true)
+}
+#[tauri::command]
+async fn import_progress(app: tauri::AppHandle, mode: ImportMode) -> ImportReport {
+    let Some(path) = app
+        .dialog()
+        .file()
+        .add_filter("Progress", &["json"])
+        .blocking_pick_file()
+        .and_then(|a| a.into_path().ok())
+    else {
+        return ImportReport::default();
+    };
+    let imported = std::fs::read_to_string(&path)
+        .map_err(|err| err.to_string())
+        .and_then(|a| serde_json::from_str::<Progress>(&a).map_err(|err| err.to_string()));
+    let imported = match imported {
+        Ok(imported) if imported.version > progress::PROGRESS_VERSION => {
+            return ImportReport {
+                progress: None,
+                errors: vec![format!(
+                    "version: {} is newer than this app",
+                    imported.version
+                )],
+            }
+        }
+        Ok(mut imported) => {
+            progress::place_packs(&mut imported, &app.state::<LevelsInfo>());
+            imported
+        }
+        Err(err) => {
+            return ImportReport {
+                progress: None,
+                errors: vec![err],
+            }
+        }
+    };
+    let errors = progress::validate(&imported, &app.state::<HashMap<usize, File>>());
+    if !errors.is_empty() {
+        return ImportReport {
+            progress: None,
+            errors,
+        };
+    }
+    let answers = match progress::import_answers(&app, &imported.answers, mode) {
+        Ok(answers) => answers,
+        Err(err) => {
+            return ImportReport {
+                progress: None,
+                errors: vec![err.to_string()],
+            }
+        }
+    };
+    let progress = match (mode, progress::read_progress(&app)) {
+        (ImportMode::Merge, Ok(Some(current))) => progress::merge(current, imported),
+        _ => imported,
+    };
+    if let Err(err) = progress::write_progress(&app, progress.clone()) {
+        return ImportReport {
+            progress: None,
+            errors: vec![err.to_string()],
+        };
+    }
+    // The log only changes once the progress it goes with is saved
+    let errors = match progress::write_answers(&app, &answers) {
+        Ok(()) => Vec::new(),
+        Err(err) => vec![format!("answers: {err}")],
+    };
+    ImportReport {
+        progress: Some(progress),
+        errors,
+    }
+}
+/// Writes the given levels as an Anki deck, with our intervals if `with_mastery`.
+/// False if cancelled
+#[tauri::command]
+async fn export_anki(
+    app: tauri::AppHandle,
+    levels: Vec<usize>,
+    with_mastery: bool,
+) -> Result<bool, String> {
+    let Some(path) = app
+        .dialog()
+        .file()
+        .add_filter("Anki deck", &["apkg"])
+        .set_file_name("nihon.apkg")
+        .blocking_save_file()
+        .and_then(|a| a.into_path().ok())
+    else {
+        return Ok(false);
+    };
+    let all_levels = app.state::<HashMap<usize, File>>();
+    let files: Vec<(usize, &File)> = levels
+        .iter()
+        .flat_map(|level| all_levels.get(level).map(|file| (*level, file)))
+        .collect();
+    let progress = match with_mastery {
+        true => progress::read_progress(&app).ok().flatten(),
+        false => None,
+    };
+    anki::write_apkg(
+        &path,
+        &files,
+        &app.state::<HashMap<String, Character>>(),
+        &app.state::<HashMap<String, VocabHash>>(),
+        &app.state::<RadicalArt>().0,
+        progress.as_ref().map(|a| &a.viewed),
+    )
+    .map_err(|err| {
+        eprintln!("failed to export anki deck: {err}");
+        err.to_string()
+    })?;
+    Ok(true)
+}
+#[tauri::command]
+fn search<'a>(
+    query: String,
+    all_kanjis: tauri::State<HashMap<String, Character>, 'a>,
+    all_vocabs: tauri::State<HashMap<String, VocabHash>, 'a>,
+    all_radicals: tauri::State<HashMap<String, RadicalHash>, 'a>,
+) -> Vec<SearchResult> {
+    search::search(&query, &all_kanjis, &all_vocabs, &all_radicals)
+}
+
+/// Kanji that look like the strokes drawn, each stroke is a list of points
+#[tauri::command]
+fn search_drawing<'a>(
+    strokes: Vec<Vec<Point>>,
+    templates: tauri::State<Templates, 'a>,
+    all_kanjis: tauri::State<HashMap<String, Character>, 'a>,
+) -> Vec<SearchResult> {
+    handwriting::recognize(strokes, &templates, &all_kanjis)
+}
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
+        .plugin(tauri_plugin_dialog::init())
         .invoke_handler(tauri::generate_handler![
             get_kanjis,
+            get_levels_info,
+            get_kanjis_range,
             get_radical,
+            get_radical_kanjis,
+            get_kanji_vocabs,
+            get_radical_neighbours,
             get_kanji_reading,
+            get_kanji_strokes,
+            get_radical_images,
+            get_strokes_range,
+            grade_stroke,
+            has_strokes,
             open_url,
             get_vocab,
-            get_vocab_range
+            get_vocab_range,
+            load_progress,
+            save_progress,
+            load_answers,
+            append_answers,
+            export_progress,
+            import_progress,
+            export_anki,
+            search,
+            search_drawing
         ])
         .setup(|app| {
             let mut radicals = HashMap::new();
-            let levels: HashMap<usize, File> =
-                serde_json::from_str(include_str!("../levels.json")).expect("invalid file");
-            let res: HashMap<String, Character> =
-                serde_json::from_str(include_str!("../kanjis.json")).expect("invalid file");
-            let vocabs: HashMap<String, VocabHash> =
-                serde_json::from_str(include_str!("../vocabulary.json")).expect("invalid file");
+            let mut levels: HashMap<usize, File> =
+                data::load(include_bytes!(concat!(env!("OUT_DIR"), "/levels.bin")));
+            let mut res: HashMap<String, Character> =
+                data::load(include_bytes!(concat!(env!("OUT_DIR"), "/kanjis.bin")));
+            let mut vocabs: HashMap<String, VocabHash> =
+                data::load(include_bytes!(concat!(env!("OUT_DIR"), "/vocabulary.bin")));
+            let mut packs = Vec::new();
+            match packs::packs_dir(app.handle()) {
+                Ok(dir) => {
+                    for pack in packs::read_packs(&dir) {
+                        packs.push(packs::add_pack(pack, &mut levels, &mut res, &mut vocabs));
+                    }
+                }
+                Err(err) => eprintln!("failed to find the packs directory: {err}"),
+            }
+            app.manage(LevelsInfo {
+                count: levels.len(),
+                packs,
+            });
             for (level, file) in &levels {
                 for radical in &file.radicals {
                     radicals.insert(
@@ -126,8 +524,51 @@ pub fn run() {
                     );
                 }
             }
+            let mut radical_kanjis: HashMap<String, Vec<String>> = HashMap::new();
+            for (kanji, info) in &res {
+                for radical in &info.wk_radicals {
+                    radical_kanjis
+                        .entry(radical.clone())
+                        .or_default()
+                        .push(kanji.clone());
+                }
+            }
+            for kanjis in radical_kanjis.values_mut() {
+                kanjis.sort_by_key(|a| (res[a].wk_level.unwrap_or(u32::MAX), a.clone()));
+            }
+            let mut kanji_vocabs: HashMap<String, Vec<String>> = HashMap::new();
+            for vocab in vocabs.keys() {
+                let mut seen = Vec::new();
+                for kanji in vocab.chars().map(String::from) {
+                    if res.contains_key(&kanji) && !seen.contains(&kanji) {
+                        kanji_vocabs
+                            .entry(kanji.clone())
+                            .or_default()
+                            .push(vocab.clone());
+                        seen.push(kanji);
+                    }
+                }
+            }
+            for words in kanji_vocabs.values_mut() {
+                words.sort_by_key(|a| (vocabs[a].wk_level.unwrap_or(u32::MAX), a.clone()));
+            }
+            let images: RadicalImages = data::load(include_bytes!(concat!(
+                env!("OUT_DIR"),
+                "/radical_images.bin"
+            )));
+            // Packs can bring radicals without artwork too
+            for missing in data::missing_images(&levels, &images) {
+                eprintln!("{missing}");
+            }
+            app.manage(RadicalArt(images));
             app.manage(vocabs);
             app.manage(radicals);
+            app.manage(KanjiVocabs(kanji_vocabs));
+            let strokes: Strokes =
+                data::load(include_bytes!(concat!(env!("OUT_DIR"), "/strokes.bin")));
+            app.manage(Templates::new(&strokes, &res));
+            app.manage(KanjiStrokes(strokes));
+            app.manage(RadicalKanjis(radical_kanjis));
             app.manage(res);
             app.manage(levels);
             Ok(())
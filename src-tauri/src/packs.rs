@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    fs, io,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+use tauri::{AppHandle, Manager};
+
+use crate::data::{Character, File, VocabHash};
+
+const PACKS_DIR: &str = "packs";
+
+/// Extra levels shipped as a json file in the packs directory
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Pack {
+    /// What the progress of the pack is saved under, the name when missing.
+    /// Renaming the file or adding other packs doesn't change it
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    pub version: String,
+    pub level_count: usize,
+    pub levels: Vec<File>,
+    /// Same shape as `kanjis.json`, `wk_level` counts from the first level of the pack.
+    /// Kanji missing here get their info from the level itself
+    #[serde(default)]
+    pub kanjis: HashMap<String, Character>,
+    /// Same shape as `vocabulary.json`, with levels counted like `kanjis`
+    #[serde(default)]
+    pub vocabulary: HashMap<String, VocabHash>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PackInfo {
+    pub id: String,
+    pub name: String,
+    pub version: String,
+    /// Where the pack starts, counting from 1 like `levels.json`
+    pub first_level: usize,
+    pub level_count: usize,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct LevelsInfo {
+    /// Bundled levels plus the ones from packs
+    pub count: usize,
+    pub packs: Vec<PackInfo>,
+}
+impl PackInfo {
+    /// Its levels counting from 0
+    pub fn levels(&self) -> Range<usize> {
+        self.first_level - 1..self.first_level - 1 + self.level_count
+    }
+}
+impl LevelsInfo {
+    /// Levels that come with the app, the packs start after them
+    pub fn bundled(&self) -> usize {
+        self.count - self.packs.iter().map(|a| a.level_count).sum::<usize>()
+    }
+    pub fn pack(&self, id: &str) -> Option<&PackInfo> {
+        self.packs.iter().find(|a| a.id == id)
+    }
+    /// The pack a level counting from 0 belongs to, None for bundled levels
+    pub fn pack_of(&self, level: usize) -> Option<&PackInfo> {
+        self.packs.iter().find(|a| a.levels().contains(&level))
+    }
+}
+
+pub fn packs_dir(app: &AppHandle) -> io::Result<PathBuf> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|err| io::Error::new(io::ErrorKind::NotFound, err.to_string()))?
+        .join(PACKS_DIR);
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+fn read_pack(path: &Path) -> Result<Pack, String> {
+    let contents = fs::read_to_string(path).map_err(|err| err.to_string())?;
+    let mut pack: Pack = serde_json::from_str(&contents).map_err(|err| err.to_string())?;
+    if pack.id.is_empty() {
+        pack.id = pack.name.clone();
+    }
+    if pack.level_count != pack.levels.len() {
+        return Err(format!(
+            "says it has {} levels but has {}",
+            pack.level_count,
+            pack.levels.len()
+        ));
+    }
+    Ok(pack)
+}
+
+/// Every pack in `dir` ordered by file name, broken ones are skipped
+/// and so is a pack with the id of one before it
+pub fn read_packs(dir: &Path) -> Vec<Pack> {
+    let mut paths: Vec<PathBuf> = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .flatten()
+            .map(|a| a.path())
+            .filter(|a| a.extension().is_some_and(|a| a == "json"))
+            .collect(),
+        Err(err) => {
+            eprintln!("failed to read packs: {err}");
+            return Vec::new();
+        }
+    };
+    paths.sort();
+    let mut ids = HashSet::new();
+    paths
+        .into_iter()
+        .flat_map(|path| match read_pack(&path) {
+            Ok(pack) if !ids.insert(pack.id.clone()) => {
+                eprintln!("skipping pack {}: id {} is taken", path.display(), pack.id);
+                None
+            }
+            Ok(pack) => Some(pack),
+            Err(err) => {
+                eprintln!("skipping pack {}: {err}", path.display());
+                None
+            }
+        })
+        .collect()
+}
+
+/// Puts the pack levels after the last one and fills the lookup maps.
+/// Items that already exist keep their bundled info
+pub fn add_pack(
+    mut pack: Pack,
+    levels: &mut HashMap<usize, File>,
+    kanjis: &mut HashMap<String, Character>,
+    vocabs: &mut HashMap<String, VocabHash>,
+) -> PackInfo {
+    let first_level = levels.keys().max().copied().unwrap_or(0) + 1;
+    let offset = first_level as u32 - 1;
+    for (index, file) in pack.levels.into_iter().enumerate() {
+        let level = (first_level + index) as u32;
+        for kanji in &file.kanjis {
+            kanjis.entry(kanji.character.clone()).or_insert_with(|| {
+                match pack.kanjis.remove(&kanji.character) {
+                    Some(mut info) => {
+                        info.wk_level = Some(info.wk_level.map_or(level, |a| a + offset));
+                        info
+                    }
+                    None => Character {
+                        strokes: 0,
+                        freq: None,
+                        wk_meanings: kanji.meanings.clone(),
+                        wk_readings_on: kanji.readings_on.clone(),
+                        wk_readings_kun: kanji.readings_kun.clone(),
+                        wk_radicals: Vec::new(),
+                        wk_level: Some(level),
+                    },
+                }
+            });
+        }
+        for vocab in &file.vocabs {
+            vocabs.entry(vocab.character.clone()).or_insert_with(|| {
+                match pack.vocabulary.remove(&vocab.character) {
+                    Some(mut info) => {
+                        info.wk_level = Some(info.wk_level.map_or(level, |a| a + offset));
+                        info
+                    }
+                    None => VocabHash {
+                        meanings: vec![vocab.meaning.clone()],
+                        wk_level: Some(level),
+                        readings: vec![vocab.reading.clone()],
+                        primary_reading: vocab.reading.clone(),
+                        primary_meaning: vocab.meaning.clone(),
+                        another_form: Vec::new(),
+                    },
+                }
+            });
+        }
+        levels.insert(level as usize, file);
+    }
+    PackInfo {
+        id: pack.id,
+        name: pack.name,
+        version: pack.version,
+        first_level,
+        level_count: pack.level_count,
+    }
+}
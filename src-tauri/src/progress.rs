@@ -0,0 +1,733 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    io::{self, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tauri::{AppHandle, Manager};
+
+use crate::{data::File, packs::LevelsInfo, settings::DEFAULT_CHOICE_WEIGHT};
+
+pub const PROGRESS_VERSION: u32 = 1;
+const PROGRESS_FILE: &str = "progress.json";
+/// The answer log, one json answer per line
+const ANSWERS_FILE: &str = "answers.jsonl";
+
+/// Everything the frontend needs to remember between launches.
+/// `viewed` is kept as it comes since its shape belongs to the frontend
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Progress {
+    #[serde(default)]
+    pub version: u32,
+    pub viewed: serde_json::Value,
+    pub level: usize,
+    pub times_learned: u32,
+    pub last_reviewed: u8,
+    pub last_learned: u32,
+    /// Answers the user added, shaped by the frontend too
+    #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
+    pub synonyms: serde_json::Value,
+    #[serde(default)]
+    pub overrides: u32,
+    /// How much a multiple choice answer counts, in percent of a typed one
+    #[serde(default = "default_choice_weight")]
+    pub choice_weight: u32,
+    /// Review left halfway, None once it is finished
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session: Option<serde_json::Value>,
+    /// The answer log, only in exported files. It lives in its own file since
+    /// it only grows, older saves had it here
+    #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
+    pub answers: serde_json::Value,
+    /// Progress in the pack levels by pack id, `viewed.levels` only has the
+    /// bundled ones on disk so adding or removing a pack moves nothing.
+    /// Packs that aren't loaded keep theirs here, see `store_packs`
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub packs: serde_json::Map<String, serde_json::Value>,
+}
+
+fn default_choice_weight() -> u32 {
+    DEFAULT_CHOICE_WEIGHT
+}
+
+fn data_path(app: &AppHandle, name: &str) -> io::Result<PathBuf> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|err| io::Error::new(io::ErrorKind::NotFound, err.to_string()))?;
+    fs::create_dir_all(&dir)?;
+    Ok(dir.join(name))
+}
+
+fn progress_path(app: &AppHandle) -> io::Result<PathBuf> {
+    data_path(app, PROGRESS_FILE)
+}
+
+/// Writes to a temporary file first so a crash never leaves half a file behind
+pub fn write_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let tmp = path.with_extension("tmp");
+    let mut file = fs::File::create(&tmp)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    fs::rename(&tmp, path)
+}
+
+/// The file as it is saved, with the pack progress under the pack ids
+pub fn read_stored(app: &AppHandle) -> io::Result<Option<Progress>> {
+    let path = progress_path(app)?;
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+    let progress: Progress = serde_json::from_str(&contents)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+    if progress.version > PROGRESS_VERSION {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "progress version {} is newer than this app",
+                progress.version
+            ),
+        ));
+    }
+    let mut progress = progress;
+    move_answers_out(app, &mut progress)?;
+    Ok(Some(progress))
+}
+
+/// Older saves kept the answer log in the progress file, it goes to the
+/// answer log and the progress file is saved without it
+fn move_answers_out(app: &AppHandle, progress: &mut Progress) -> io::Result<()> {
+    let mut answers = match progress.answers.take() {
+        serde_json::Value::Array(answers) => answers,
+        _ => Vec::new(),
+    };
+    // Where the pack answers were for a while
+    for entry in progress.packs.values_mut() {
+        if let Some(serde_json::Value::Array(pack_answers)) =
+            entry.as_object_mut().and_then(|a| a.remove("answers"))
+        {
+            answers.extend(pack_answers);
+        }
+    }
+    if answers.is_empty() {
+        return Ok(());
+    }
+    answers.sort_by_key(|a| a["time"].as_i64().unwrap_or(0));
+    append_answers(app, &answers)?;
+    let contents = serde_json::to_vec_pretty(&progress)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+    write_atomic(&progress_path(app)?, &contents)
+}
+
+/// The saved progress with the pack levels where the packs are loaded now
+pub fn read_progress(app: &AppHandle) -> io::Result<Option<Progress>> {
+    let mut progress = read_stored(app)?;
+    if let Some(progress) = &mut progress {
+        place_packs(progress, &app.state::<LevelsInfo>());
+    }
+    Ok(progress)
+}
+
+pub fn write_progress(app: &AppHandle, mut progress: Progress) -> io::Result<()> {
+    progress.version = PROGRESS_VERSION;
+    progress.answers = serde_json::Value::Null;
+    // The frontend only knows the loaded packs, the others are in the file
+    let kept = match read_stored(app) {
+        Ok(Some(stored)) => stored.packs,
+        _ => serde_json::Map::new(),
+    };
+    store_packs(&mut progress, &app.state::<LevelsInfo>(), kept);
+    let path = progress_path(app)?;
+    let contents = serde_json::to_vec_pretty(&progress)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+    write_atomic(&path, &contents)
+}
+
+/// Copies an unreadable file next to it, named after the time so an older
+/// backup is never overwritten. Returns where the copy went
+pub fn backup_progress(app: &AppHandle) -> io::Result<PathBuf> {
+    let path = progress_path(app)?;
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|a| a.as_secs())
+        .unwrap_or_default();
+    let backup = path.with_extension(format!("{time}.json.bak"));
+    fs::copy(&path, &backup)?;
+    Ok(backup)
+}
+
+fn empty_level() -> serde_json::Value {
+    serde_json::json!({ "kanjis": [], "radicals": [], "vocabs": [] })
+}
+
+fn item_level(item: &serde_json::Value) -> Option<usize> {
+    item.get("level")
+        .and_then(|a| a.as_u64())
+        .map(|a| a as usize)
+}
+
+/// Changes the `level` of a question or an answer to count from the start
+/// of its pack, with the pack id next to it. Bundled levels stay as they are
+fn unplace_level(item: &mut serde_json::Value, info: &LevelsInfo) {
+    if item.get("pack").is_some() {
+        return;
+    }
+    let Some((level, pack)) =
+        item_level(item).and_then(|level| info.pack_of(level).map(|pack| (level, pack)))
+    else {
+        return;
+    };
+    item["level"] = serde_json::json!(level + 1 - pack.first_level);
+    item["pack"] = serde_json::json!(pack.id);
+}
+
+/// Opposite of `unplace_level`, false if the pack isn't loaded
+fn place_level(item: &mut serde_json::Value, info: &LevelsInfo) -> bool {
+    let Some(id) = item.get("pack").and_then(|a| a.as_str()) else {
+        return true;
+    };
+    let Some(pack) = info.pack(id) else {
+        return false;
+    };
+    let Some(level) = item_level(item).filter(|a| *a < pack.level_count) else {
+        return false;
+    };
+    item["level"] = serde_json::json!(pack.first_level - 1 + level);
+    if let Some(item) = item.as_object_mut() {
+        item.remove("pack");
+    }
+    true
+}
+
+/// Every question and result of a session
+fn session_items(session: &mut serde_json::Value) -> Vec<&mut serde_json::Value> {
+    let Some(session) = session.as_object_mut() else {
+        return Vec::new();
+    };
+    session
+        .iter_mut()
+        .filter(|(key, _)| *key == "queue" || *key == "results")
+        .flat_map(|(_, list)| list.as_array_mut().into_iter().flatten())
+        .collect()
+}
+
+/// Moves what belongs to the loaded packs under their ids, the way it is
+/// saved. `kept` is the pack progress saved before, the packs that aren't
+/// loaded keep theirs
+pub fn store_packs(
+    progress: &mut Progress,
+    info: &LevelsInfo,
+    kept: serde_json::Map<String, serde_json::Value>,
+) {
+    for (id, entry) in kept {
+        if info.pack(&id).is_none() {
+            progress.packs.entry(id).or_insert(entry);
+        }
+    }
+    let bundled = info.bundled();
+    let mut levels = match progress
+        .viewed
+        .get_mut("levels")
+        .and_then(|a| a.as_array_mut())
+    {
+        Some(levels) if levels.len() > bundled => levels.split_off(bundled),
+        _ => Vec::new(),
+    };
+    for pack in &info.packs {
+        let start = pack.first_level - 1 - bundled;
+        let pack_levels: Vec<serde_json::Value> = (start..start + pack.level_count)
+            .map(|a| {
+                levels
+                    .get_mut(a)
+                    .map(std::mem::take)
+                    .unwrap_or_else(empty_level)
+            })
+            .collect();
+        let mut entry = serde_json::json!({ "levels": pack_levels });
+        if pack.levels().contains(&progress.level) {
+            entry["level"] = serde_json::json!(progress.level + 1 - pack.first_level);
+        }
+        progress.packs.insert(pack.id.clone(), entry);
+    }
+    if info.pack_of(progress.level).is_some() {
+        // Where a pack that is gone leaves the selection
+        progress.level = bundled.saturating_sub(1);
+    }
+    if let Some(session) = &mut progress.session {
+        for item in session_items(session) {
+            unplace_level(item, info);
+        }
+    }
+}
+
+/// Opposite of `store_packs`, puts the progress of the loaded packs where
+/// they are now. A session with questions of a pack that is gone is dropped
+pub fn place_packs(progress: &mut Progress, info: &LevelsInfo) {
+    // Older saves kept the pack levels in `viewed.levels` as they were
+    if progress.packs.is_empty() {
+        return;
+    }
+    let Some(levels) = progress
+        .viewed
+        .get_mut("levels")
+        .and_then(|a| a.as_array_mut())
+    else {
+        return;
+    };
+    levels.resize_with(info.bundled(), empty_level);
+    for pack in &info.packs {
+        let mut entry = progress.packs.remove(&pack.id).unwrap_or_default();
+        let mut pack_levels = match entry.get_mut("levels").map(serde_json::Value::take) {
+            Some(serde_json::Value::Array(pack_levels)) => pack_levels,
+            _ => Vec::new(),
+        };
+        pack_levels.resize_with(pack.level_count, empty_level);
+        levels.extend(pack_levels);
+        if let Some(level) = item_level(&entry).filter(|a| *a < pack.level_count) {
+            progress.level = pack.first_level - 1 + level;
+        }
+    }
+    if let Some(session) = &mut progress.session {
+        let mut items = session_items(session);
+        if !items.iter_mut().all(|item| place_level(item, info)) {
+            progress.session = None;
+        }
+    }
+}
+
+/// Lines for the answer log, pack answers keep the pack id like `store_packs`
+fn answer_lines(answers: &[serde_json::Value], info: &LevelsInfo) -> String {
+    let mut lines = String::new();
+    for answer in answers {
+        let mut answer = answer.clone();
+        unplace_level(&mut answer, info);
+        lines.push_str(&answer.to_string());
+        lines.push('\n');
+    }
+    lines
+}
+
+/// Every answer in the log as it is saved. A line cut short by a crash is skipped
+fn parse_answers(contents: &str) -> Vec<serde_json::Value> {
+    contents
+        .lines()
+        .filter(|a| !a.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str(line) {
+            Ok(answer) => Some(answer),
+            Err(err) => {
+                eprintln!("skipping answer: {err}");
+                None
+            }
+        })
+        .collect()
+}
+
+fn read_stored_answers(app: &AppHandle) -> io::Result<Vec<serde_json::Value>> {
+    match fs::read_to_string(data_path(app, ANSWERS_FILE)?) {
+        Ok(contents) => Ok(parse_answers(&contents)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(err),
+    }
+}
+
+/// The answer log with the pack answers where the packs are loaded now,
+/// answers of packs that aren't loaded are left out
+pub fn read_answers(app: &AppHandle) -> io::Result<Vec<serde_json::Value>> {
+    let info = app.state::<LevelsInfo>();
+    Ok(read_stored_answers(app)?
+        .into_iter()
+        .filter_map(|mut a| place_level(&mut a, &info).then_some(a))
+        .collect())
+}
+
+/// Adds to the end of the answer log, what is there is never written again
+pub fn append_answers(app: &AppHandle, answers: &[serde_json::Value]) -> io::Result<()> {
+    let mut lines = answer_lines(answers, &app.state::<LevelsInfo>());
+    let mut file = fs::OpenOptions::new()
+        .read(true)
+        .append(true)
+        .create(true)
+        .open(data_path(app, ANSWERS_FILE)?)?;
+    // A crash could have left the last line without its end
+    if file.metadata()?.len() > 0 {
+        let mut last = [0u8];
+        file.seek(SeekFrom::End(-1))?;
+        file.read_exact(&mut last)?;
+        if last[0] != b'\n' {
+            lines.insert(0, '\n');
+        }
+    }
+    file.write_all(lines.as_bytes())?;
+    file.sync_all()
+}
+
+/// The answer log as it is saved, for exporting
+pub fn export_answers(app: &AppHandle) -> io::Result<serde_json::Value> {
+    Ok(serde_json::Value::Array(read_stored_answers(app)?))
+}
+
+/// The answer log with the answers of an imported file, next to the ones
+/// already there when merging. Nothing is written, see `write_answers`
+pub fn import_answers(
+    app: &AppHandle,
+    imported: &serde_json::Value,
+    mode: ImportMode,
+) -> io::Result<String> {
+    let info = app.state::<LevelsInfo>();
+    // Answers of pack levels get their pack id before comparing
+    let imported: Vec<serde_json::Value> = imported
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|answer| {
+            let mut answer = answer.clone();
+            unplace_level(&mut answer, &info);
+            answer
+        })
+        .collect();
+    let mut answers = match mode {
+        ImportMode::Merge => serde_json::Value::Array(read_stored_answers(app)?),
+        ImportMode::Replace => serde_json::json!([]),
+    };
+    merge_answers(&mut answers, &serde_json::Value::Array(imported));
+    let answers = answers.as_array().map(|a| a.as_slice()).unwrap_or_default();
+    Ok(answer_lines(answers, &info))
+}
+
+/// Replaces the whole answer log with the lines of `import_answers`
+pub fn write_answers(app: &AppHandle, lines: &str) -> io::Result<()> {
+    write_atomic(&data_path(app, ANSWERS_FILE)?, lines.as_bytes())
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ImportMode {
+    /// Keeps what is already learned and adds what is new
+    Merge,
+    /// Throws away the current progress
+    Replace,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct ImportReport {
+    /// None if cancelled or invalid
+    pub progress: Option<Progress>,
+    /// Why the file was refused, or what failed after the progress was saved
+    pub errors: Vec<String>,
+}
+
+const KINDS: [&str; 3] = ["kanjis", "radicals", "vocabs"];
+
+fn items<'a>(viewed: &'a serde_json::Value, level: usize, kind: &str) -> &'a [serde_json::Value] {
+    viewed["levels"][level][kind]
+        .as_array()
+        .map(|a| a.as_slice())
+        .unwrap_or_default()
+}
+
+fn levels_len(viewed: &serde_json::Value) -> usize {
+    viewed["levels"].as_array().map(|a| a.len()).unwrap_or(0)
+}
+
+/// Mastery of an item, old saves only had the number
+fn stage(item: &serde_json::Value) -> u64 {
+    match &item[1] {
+        serde_json::Value::Number(stage) => stage.as_u64().unwrap_or(0),
+        state => state["stage"].as_u64().unwrap_or(0),
+    }
+}
+
+/// The state is a plain number in saves older than the scheduler
+fn valid_state(state: &serde_json::Value) -> bool {
+    state.is_u64()
+        || ["stage", "interval", "ease", "due"]
+            .iter()
+            .all(|key| state[*key].is_number())
+}
+
+/// Every item that is not part of its level in `levels.json`, with its path
+pub fn validate(progress: &Progress, levels: &HashMap<usize, File>) -> Vec<String> {
+    let mut errors = Vec::new();
+    if !progress.viewed["levels"].is_array() {
+        errors.push(String::from("viewed.levels: expected a list of levels"));
+        return errors;
+    }
+    if !progress.synonyms.is_null() && !progress.synonyms.is_object() {
+        errors.push(String::from("synonyms: expected an object"));
+    }
+    if !progress.answers.is_null() && !progress.answers.is_array() {
+        errors.push(String::from("answers: expected a list"));
+    }
+    if progress.level >= levels_len(&progress.viewed) {
+        errors.push(format!("level: {} does not exist", progress.level + 1));
+    }
+    for level in 0..levels_len(&progress.viewed) {
+        let file = levels.get(&(level + 1));
+        for kind in KINDS {
+            for (index, item) in items(&progress.viewed, level, kind).iter().enumerate() {
+                let path = format!("viewed.levels[{level}].{kind}[{index}]");
+                let Some(identifier) = item[0].as_str().filter(|_| valid_state(&item[1])) else {
+                    errors.push(format!("{path}: expected [identifier, state]"));
+                    continue;
+                };
+                let exists = file
+                    .map(|file| match kind {
+                        "kanjis" => file.kanjis.iter().any(|a| a.character == identifier),
+                        "radicals" => file.radicals.iter().any(|a| a.meaning == identifier),
+                        _ => file.vocabs.iter().any(|a| a.character == identifier),
+                    })
+                    .unwrap_or(false);
+                if !exists {
+                    errors.push(format!(
+                        "{path}: {identifier} is not part of level {}",
+                        level + 1
+                    ));
+                }
+            }
+        }
+    }
+    errors
+}
+
+/// Keeps every synonym from both sides
+fn merge_synonyms(current: &mut serde_json::Value, imported: &serde_json::Value) {
+    let Some(imported) = imported.as_object() else {
+        return;
+    };
+    if !current.is_object() {
+        *current = serde_json::json!({});
+    }
+    for (kind, items) in imported {
+        let Some(items) = items.as_object() else {
+            continue;
+        };
+        for (identifier, item) in items {
+            for list in ["meanings", "readings"] {
+                let Some(values) = item.get(list).and_then(|a| a.as_array()) else {
+                    continue;
+                };
+                let Some(existing) = current
+                    .as_object_mut()
+                    .map(|a| a.entry(kind).or_insert(serde_json::json!({})))
+                    .and_then(|a| a.as_object_mut())
+                    .map(|a| a.entry(identifier).or_insert(serde_json::json!({})))
+                    .and_then(|a| a.as_object_mut())
+                    .map(|a| a.entry(list).or_insert(serde_json::json!([])))
+                    .and_then(|a| a.as_array_mut())
+                else {
+                    continue;
+                };
+                for value in values {
+                    if !existing.contains(value) {
+                        existing.push(value.clone());
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Adds the answers only `imported` has, keeping the log in time order
+fn merge_answers(current: &mut serde_json::Value, imported: &serde_json::Value) {
+    let Some(imported) = imported.as_array() else {
+        return;
+    };
+    if !current.is_array() {
+        *current = serde_json::json!([]);
+    }
+    let Some(answers) = current.as_array_mut() else {
+        return;
+    };
+    // Logs get long, comparing every pair would take a while
+    let mut known: HashSet<String> = answers.iter().map(|a| a.to_string()).collect();
+    for answer in imported {
+        if known.insert(answer.to_string()) {
+            answers.push(answer.clone());
+        }
+    }
+    answers.sort_by_key(|a| a["time"].as_i64().unwrap_or(0));
+}
+
+/// Adds the items only `imported` has and keeps the most mastered state
+/// for items both have. The daily counters and the overrides stay as they are
+pub fn merge(mut current: Progress, imported: Progress) -> Progress {
+    merge_synonyms(&mut current.synonyms, &imported.synonyms);
+    // Packs that aren't loaded, `place_packs` took the others out
+    for (id, entry) in imported.packs {
+        current.packs.entry(id).or_insert(entry);
+    }
+    if let Some(levels) = current
+        .viewed
+        .get_mut("levels")
+        .and_then(|a| a.as_array_mut())
+    {
+        while levels.len() < levels_len(&imported.viewed) {
+            levels.push(serde_json::json!({ "kanjis": [], "radicals": [], "vocabs": [] }));
+        }
+    }
+    for level in 0..levels_len(&imported.viewed) {
+        for kind in KINDS {
+            for item in items(&imported.viewed, level, kind) {
+                let Some(list) = current
+                    .viewed
+                    .get_mut("levels")
+                    .and_then(|a| a.get_mut(level))
+                    .and_then(|a| a.get_mut(kind))
+                    .and_then(|a| a.as_array_mut())
+                else {
+                    continue;
+                };
+                match list.iter_mut().find(|a| a[0] == item[0]) {
+                    Some(existing) if stage(existing) < stage(item) => *existing = item.clone(),
+                    Some(_) => {}
+                    None => list.push(item.clone()),
+                }
+            }
+        }
+    }
+    current
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packs::PackInfo;
+
+    const BUNDLED: usize = 2;
+
+    /// Packs loaded in this order after the bundled levels
+    fn levels_info(packs: &[(&str, usize)]) -> LevelsInfo {
+        let mut first_level = BUNDLED + 1;
+        let packs: Vec<PackInfo> = packs
+            .iter()
+            .map(|(id, level_count)| {
+                let pack = PackInfo {
+                    id: id.to_string(),
+                    name: id.to_string(),
+                    version: String::from("1"),
+                    first_level,
+                    level_count: *level_count,
+                };
+                first_level += level_count;
+                pack
+            })
+            .collect();
+        LevelsInfo {
+            count: first_level - 1,
+            packs,
+        }
+    }
+
+    /// One kanji learned in each level, named after it
+    fn progress(kanjis: &[&str], level: usize) -> Progress {
+        let levels: Vec<serde_json::Value> = kanjis
+            .iter()
+            .map(|a| serde_json::json!({ "kanjis": [[a, 1]], "radicals": [], "vocabs": [] }))
+            .collect();
+        serde_json::from_value(serde_json::json!({
+            "viewed": { "levels": levels },
+            "level": level,
+            "times_learned": 0,
+            "last_reviewed": 0,
+            "last_learned": 0,
+        }))
+        .unwrap()
+    }
+
+    fn kanjis(progress: &Progress) -> Vec<String> {
+        (0..levels_len(&progress.viewed))
+            .map(|level| {
+                items(&progress.viewed, level, "kanjis")
+                    .first()
+                    .and_then(|a| a[0].as_str())
+                    .unwrap_or("")
+                    .to_string()
+            })
+            .collect()
+    }
+
+    /// Saves with `before` loaded and loads it again with `after`
+    fn reload(progress: Progress, before: &LevelsInfo, after: &LevelsInfo) -> Progress {
+        let mut stored = progress;
+        store_packs(&mut stored, before, serde_json::Map::new());
+        let mut stored: Progress =
+            serde_json::from_str(&serde_json::to_string(&stored).unwrap()).unwrap();
+        assert_eq!(levels_len(&stored.viewed), BUNDLED);
+        place_packs(&mut stored, after);
+        stored
+    }
+
+    #[test]
+    fn removing_a_pack_keeps_the_others() {
+        let before = levels_info(&[("a", 1), ("b", 2)]);
+        let current = progress(&["k1", "k2", "a1", "b1", "b2"], 4);
+        let after = levels_info(&[("b", 2)]);
+        let loaded = reload(current, &before, &after);
+        assert_eq!(kanjis(&loaded), ["k1", "k2", "b1", "b2"]);
+        assert_eq!(loaded.level, 3);
+        assert!(loaded.packs.contains_key("a"));
+    }
+
+    #[test]
+    fn adding_a_pack_keeps_the_others() {
+        let before = levels_info(&[("b", 1)]);
+        let after = levels_info(&[("a", 2), ("b", 1)]);
+        let loaded = reload(progress(&["k1", "k2", "b1"], 2), &before, &after);
+        assert_eq!(kanjis(&loaded), ["k1", "k2", "", "", "b1"]);
+        assert_eq!(loaded.level, 4);
+    }
+
+    #[test]
+    fn a_removed_pack_comes_back_with_its_progress() {
+        let both = levels_info(&[("a", 1), ("b", 1)]);
+        let only_b = levels_info(&[("b", 1)]);
+        let mut current = progress(&["k1", "k2", "a1", "b1"], 0);
+        store_packs(&mut current, &both, serde_json::Map::new());
+        let kept = current.packs.clone();
+        place_packs(&mut current, &only_b);
+        assert_eq!(kanjis(&current), ["k1", "k2", "b1"]);
+        // The frontend doesn't send the packs back
+        current.packs.clear();
+        store_packs(&mut current, &only_b, kept);
+        place_packs(&mut current, &both);
+        assert_eq!(kanjis(&current), ["k1", "k2", "a1", "b1"]);
+    }
+
+    #[test]
+    fn a_session_of_a_removed_pack_is_dropped() {
+        let before = levels_info(&[("a", 1)]);
+        let mut current = progress(&["k1", "k2", "a1"], 0);
+        current.session = Some(serde_json::json!({ "queue": [{ "level": 2 }], "results": [] }));
+        let loaded = reload(current.clone(), &before, &before);
+        assert_eq!(loaded.session, current.session);
+        let loaded = reload(current, &before, &levels_info(&[]));
+        assert_eq!(loaded.session, None);
+    }
+
+    #[test]
+    fn answers_follow_their_pack() {
+        let before = levels_info(&[("a", 1), ("b", 1)]);
+        let answers = [
+            serde_json::json!({ "time": 1, "level": 0, "identifier": "k1" }),
+            serde_json::json!({ "time": 2, "level": 3, "identifier": "b1" }),
+        ];
+        // Cut short by a crash
+        let lines = answer_lines(&answers, &before) + "{\"time\": 3, \"le";
+        let after = levels_info(&[("b", 1)]);
+        let loaded: Vec<serde_json::Value> = parse_answers(&lines)
+            .into_iter()
+            .filter_map(|mut a| place_level(&mut a, &after).then_some(a))
+            .collect();
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded[0], answers[0]);
+        assert_eq!(loaded[1]["level"], 2);
+        let loaded: Vec<serde_json::Value> = parse_answers(&lines)
+            .into_iter()
+            .filter_map(|mut a| place_level(&mut a, &levels_info(&[])).then_some(a))
+            .collect();
+        assert_eq!(loaded, answers[..1]);
+    }
+}
@@ -0,0 +1,174 @@
+use rust_fuzzy_search::fuzzy_compare;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use wana_kana::{ConvertJapanese, IsJapaneseStr};
+
+use crate::{
+    data::{Character, VocabHash},
+    RadicalHash,
+};
+
+const MAX_RESULTS: usize = 50;
+const MIN_SIMILARITY: f32 = 0.6;
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SearchKind {
+    Kanji,
+    Vocabulary,
+    Radical,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SearchResult {
+    pub kind: SearchKind,
+    /// What the info page is keyed by, the meaning for radicals
+    pub identifier: String,
+    pub character: String,
+    pub meaning: String,
+    pub reading: String,
+    pub wk_level: Option<u32>,
+    pub score: f32,
+}
+
+fn clean(value: &str) -> String {
+    value.trim_start_matches(['^', '!']).to_lowercase()
+}
+
+/// How well `query` matches one of `values`, 0 if it doesn't
+fn best_score<'a>(query: &str, values: impl Iterator<Item = &'a String>, fuzzy: bool) -> f32 {
+    values
+        .map(|value| {
+            let value = clean(value);
+            if value == query {
+                2.0
+            } else if value.starts_with(query) {
+                1.5
+            } else if value.split([' ', '-']).any(|word| word == query) {
+                1.2
+            } else if fuzzy {
+                let similarity = fuzzy_compare(query, &value);
+                if similarity >= MIN_SIMILARITY {
+                    similarity
+                } else {
+                    0.0
+                }
+            } else {
+                0.0
+            }
+        })
+        .fold(0.0, f32::max)
+}
+
+/// Readings are compared in hiragana so romaji, hiragana and katakana all work
+fn reading_score<'a>(kana: &str, readings: impl Iterator<Item = &'a String>) -> f32 {
+    let readings: Vec<String> = readings.map(|a| clean(a).to_hiragana()).collect();
+    best_score(kana, readings.iter(), false)
+}
+
+/// A kanji as shown in the results
+pub fn kanji_result(character: &str, info: &Character, score: f32) -> SearchResult {
+    SearchResult {
+        kind: SearchKind::Kanji,
+        identifier: character.to_string(),
+        character: character.to_string(),
+        meaning: info
+            .wk_meanings
+            .first()
+            .map(|a| clean(a))
+            .unwrap_or_default(),
+        reading: info
+            .wk_readings_on
+            .first()
+            .or(info.wk_readings_kun.first())
+            .map(|a| clean(a))
+            .unwrap_or_default(),
+        wk_level: info.wk_level,
+        score,
+    }
+}
+
+/// Matches the character, the romaji or kana reading, and the english meaning
+pub fn search(
+    query: &str,
+    kanjis: &HashMap<String, Character>,
+    vocabs: &HashMap<String, VocabHash>,
+    radicals: &HashMap<String, RadicalHash>,
+) -> Vec<SearchResult> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let kana = query.to_hiragana();
+    let is_kana = kana.is_kana();
+    let mut results = Vec::new();
+
+    for (character, info) in kanjis {
+        let score = if *character == query {
+            3.0
+        } else {
+            let readings = info
+                .wk_readings_on
+                .iter()
+                .chain(info.wk_readings_kun.iter());
+            let reading = match is_kana {
+                true => reading_score(&kana, readings),
+                false => 0.0,
+            };
+            reading.max(best_score(&query, info.wk_meanings.iter(), true))
+        };
+        if score > 0.0 {
+            results.push(kanji_result(character, info, score));
+        }
+    }
+    for (character, info) in vocabs {
+        let score = if *character == query || info.another_form.contains(&query) {
+            3.0
+        } else {
+            let reading = match is_kana {
+                true => reading_score(&kana, info.readings.iter()),
+                false => 0.0,
+            };
+            let meanings = std::iter::once(&info.primary_meaning).chain(info.meanings.iter());
+            reading.max(best_score(&query, meanings, true))
+        };
+        if score > 0.0 {
+            results.push(SearchResult {
+                kind: SearchKind::Vocabulary,
+                identifier: character.clone(),
+                character: character.clone(),
+                meaning: info.primary_meaning.clone(),
+                reading: info.primary_reading.clone(),
+                wk_level: info.wk_level,
+                score,
+            });
+        }
+    }
+    for (meaning, info) in radicals {
+        let score = if info.character == query {
+            3.0
+        } else {
+            best_score(&query, std::iter::once(meaning), true)
+        };
+        if score > 0.0 {
+            results.push(SearchResult {
+                kind: SearchKind::Radical,
+                identifier: meaning.clone(),
+                character: info.character.clone(),
+                meaning: meaning.clone(),
+                reading: String::new(),
+                wk_level: info.wk_level,
+                score,
+            });
+        }
+    }
+
+    results.sort_by(|a, b| {
+        b.score.total_cmp(&a.score).then(
+            a.wk_level
+                .unwrap_or(u32::MAX)
+                .cmp(&b.wk_level.unwrap_or(u32::MAX)),
+        )
+    });
+    results.truncate(MAX_RESULTS);
+    results
+}
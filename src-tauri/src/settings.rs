@@ -0,0 +1,5 @@
+//! Defaults of the settings kept in the progress file. The frontend includes
+//! this file too so both sides fill in the same values
+
+/// How much a multiple choice answer counts, in percent of a typed one
+pub const DEFAULT_CHOICE_WEIGHT: u32 = 50;
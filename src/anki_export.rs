@@ -0,0 +1,74 @@
+use leptos::*;
+use serde::Serialize;
+use wasm_bindgen::UnwrapThrowExt;
+
+use crate::{invoke::invoke_result, utils::alert_errors, ViewedContext};
+
+/// Levels start at 1 like in `get_kanjis`, false if cancelled
+pub async fn export_anki(levels: Vec<usize>, with_mastery: bool) -> Result<bool, String> {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct T {
+        levels: Vec<usize>,
+        with_mastery: bool,
+    }
+    invoke_result(
+        "export_anki",
+        T {
+            levels,
+            with_mastery,
+        },
+    )
+    .await
+}
+
+#[component]
+pub fn AnkiExport() -> impl IntoView {
+    let level = use_context::<Signal<usize>>().expect_throw("level");
+    let (viewed, _) = use_context::<ViewedContext>().expect_throw("viewed context");
+    let with_mastery = create_rw_signal(true);
+    let export = move |levels: Vec<usize>| {
+        let with_mastery = with_mastery.get_untracked();
+        spawn_local(async move {
+            if let Err(err) = export_anki(levels, with_mastery).await {
+                alert_errors("Couldn't export the deck", &[err]);
+            }
+        });
+    };
+    let learned_levels = move || {
+        viewed.with(|viewed| {
+            viewed
+                .levels
+                .iter()
+                .enumerate()
+                .filter(|(_, a)| {
+                    !a.kanjis.is_empty() || !a.radicals.is_empty() || !a.vocabs.is_empty()
+                })
+                .map(|(index, _)| index + 1)
+                .collect::<Vec<usize>>()
+        })
+    };
+    view! {
+        <section class="transfer">
+            <h3>
+                Anki
+            </h3>
+            <div>
+                <button on:click=move |_| export(vec![level.get() + 1])>
+                    Export this level
+                </button>
+                <button on:click=move |_| export(learned_levels())>
+                    Export every learned level
+                </button>
+                <label>
+                    <input
+                        type="checkbox"
+                        prop:checked=move || with_mastery.get()
+                        on:change=move |ev| with_mastery.set(event_target_checked(&ev))
+                    />
+                    Keep my intervals
+                </label>
+            </div>
+        </section>
+    }
+}
@@ -1,21 +1,35 @@
 use crate::{
-    home::{File, Home, UPPER_KANJI, UPPER_RADICAL, UPPER_VOCABULARY},
+    choices::ChoiceWeightContext,
+    home::{File, Home, MasteryType, UPPER_KANJI, UPPER_RADICAL, UPPER_VOCABULARY},
     invoke::{get_kanjis, invokers},
     kanji_info::KanjiInfo,
-    learningkanji::LearningKanji,
+    learningkanji::{LearningKanji, Session},
+    leeches::find_leeches,
+    packs::{get_levels_info, LevelsInfo, BUNDLED_LEVELS},
+    progress::{load_progress, save_progress, ApplyProgressContext, Progress},
+    radical_image::get_radical_images,
     radical_info::RadicalInfo,
-    utils::local_get,
+    search::Search,
+    settings::DEFAULT_CHOICE_WEIGHT,
+    srs::Srs,
+    stats::{load_answers, Answer, Stats},
+    synonyms::Synonyms,
     vocab_info::VocabInfo,
 };
 use chrono::Datelike;
 use leptos::*;
 use leptos_router::{use_location, Route, Routes, A};
-use leptos_use::storage::use_local_storage;
 use serde::{Deserialize, Serialize};
 use std::{str::FromStr, time::Duration};
 use wasm_bindgen::UnwrapThrowExt;
 pub type ViewedContext = (Signal<Viewed>, WriteSignal<Viewed>);
 pub type LastReviewedContext = (Signal<u8>, WriteSignal<u8>);
+/// Its own type since `(Signal<u32>, WriteSignal<u32>)` is already times_learned
+#[derive(Clone, Copy)]
+pub struct LastLearnedContext(pub Signal<u32>, pub WriteSignal<u32>);
+/// How many answers were counted as correct after being marked wrong
+#[derive(Clone, Copy)]
+pub struct OverridesContext(pub Signal<u32>, pub WriteSignal<u32>);
 pub const LIMIT: u32 = 15;
 
 #[derive(Serialize, Deserialize)]
@@ -24,9 +38,25 @@ struct GreetArgs<'a> {
 }
 #[derive(Clone, PartialEq, Eq, Default, Deserialize, Serialize, Debug)]
 pub struct ViewedList {
-    pub kanjis: Vec<(String, usize)>,
-    pub radicals: Vec<(String, usize)>,
-    pub vocabs: Vec<(String, usize)>,
+    pub kanjis: Vec<(String, Srs)>,
+    pub radicals: Vec<(String, Srs)>,
+    pub vocabs: Vec<(String, Srs)>,
+}
+impl ViewedList {
+    pub fn of_type(&self, r#type: MasteryType) -> &Vec<(String, Srs)> {
+        match r#type {
+            MasteryType::Kanji => &self.kanjis,
+            MasteryType::Radical => &self.radicals,
+            MasteryType::Vocabulary => &self.vocabs,
+        }
+    }
+    pub fn of_type_mut(&mut self, r#type: MasteryType) -> &mut Vec<(String, Srs)> {
+        match r#type {
+            MasteryType::Kanji => &mut self.kanjis,
+            MasteryType::Radical => &mut self.radicals,
+            MasteryType::Vocabulary => &mut self.vocabs,
+        }
+    }
 }
 #[derive(Clone, PartialEq, Eq, Deserialize, Serialize, Debug)]
 pub struct Viewed {
@@ -35,7 +65,7 @@ pub struct Viewed {
 impl Default for Viewed {
     fn default() -> Self {
         Self {
-            levels: vec![ViewedList::default(); 60],
+            levels: vec![ViewedList::default(); BUNDLED_LEVELS],
         }
     }
 }
@@ -109,9 +139,9 @@ pub fn has_acess_logic(level: usize, kanjis: &Option<File>, viewed: &Viewed) ->
     };
 
     let is_expected = viewed.levels.get(level - 1).map(|a| {
-        let total_kanji_mastery = a.kanjis.iter().fold(0, |acc, value| acc + value.1);
-        let total_vocab_mastery = a.vocabs.iter().fold(0, |acc, value| acc + value.1);
-        let total_radical_mastery = a.radicals.iter().fold(0, |acc, value| acc + value.1);
+        let total_kanji_mastery = a.kanjis.iter().fold(0, |acc, value| acc + value.1.stage);
+        let total_vocab_mastery = a.vocabs.iter().fold(0, |acc, value| acc + value.1.stage);
+        let total_radical_mastery = a.radicals.iter().fold(0, |acc, value| acc + value.1.stage);
         let expected_kanji_mastery = level_info.kanjis.len() * UPPER_KANJI;
         let expected_vocab_mastery = level_info.vocabs.len() * UPPER_VOCABULARY;
         let expected_radical_mastery = level_info.radicals.len() * UPPER_RADICAL;
@@ -146,20 +176,84 @@ pub fn today() -> u32 {
 #[component]
 pub fn App() -> impl IntoView {
     let location = use_location();
-    let (level, set_level, _) =
-        use_local_storage::<usize, leptos_use::utils::FromToStringCodec>("level");
-    let (viewed, set_viewed, _) =
-        use_local_storage::<Viewed, leptos_use::utils::FromToStringCodec>("viewed");
-    let (times_learned, set_times_learned, _) =
-        use_local_storage::<u32, leptos_use::utils::FromToStringCodec>("times_learned");
-    let (last_reviewed, set_last_reviewed, _) =
-        use_local_storage::<u8, leptos_use::utils::FromToStringCodec>("last_reviewed");
+    let (level, set_level) = create_signal(0usize);
+    let (viewed, set_viewed) = create_signal(Viewed::default());
+    let (times_learned, set_times_learned) = create_signal(0u32);
+    let (last_reviewed, set_last_reviewed) = create_signal(0u8);
+    let (last_learned, set_last_learned) = create_signal(0u32);
+    let (synonyms, set_synonyms) = create_signal(Synonyms::default());
+    let (overrides, set_overrides) = create_signal(0u32);
+    let (choice_weight, set_choice_weight) = create_signal(DEFAULT_CHOICE_WEIGHT);
+    let (session, set_session) = create_signal(None::<Session>);
+    let (answers, set_answers) = create_signal(Vec::<Answer>::new());
+    let leeches = create_memo(move |_| answers.with(|answers| find_leeches(answers)));
+    let (levels_info, set_levels_info) = create_signal(LevelsInfo::default());
+    let loaded = create_rw_signal(false);
+    let apply_progress: ApplyProgressContext = Callback::new(move |mut progress: Progress| {
+        // Packs added since the last save need room too
+        let count = levels_info.get_untracked().count;
+        if progress.viewed.levels.len() < count {
+            progress.viewed.levels.resize(count, ViewedList::default());
+        }
+        batch(move || {
+            set_level.set(progress.level);
+            set_viewed.set(progress.viewed);
+            set_times_learned.set(progress.times_learned);
+            set_last_reviewed.set(progress.last_reviewed);
+            set_last_learned.set(progress.last_learned);
+            set_synonyms.set(progress.synonyms);
+            set_overrides.set(progress.overrides);
+            set_choice_weight.set(progress.choice_weight);
+            set_session.set(progress.session);
+            loaded.set(true);
+        });
+    });
+    spawn_local(async move {
+        set_levels_info.set(get_levels_info().await);
+        let progress = match load_progress().await {
+            Ok(Some(progress)) => progress,
+            // Only a first run takes what the older versions left in localStorage
+            Ok(None) => {
+                let progress = Progress::from_local_storage();
+                save_progress(progress.clone()).await;
+                progress
+            }
+            // Nothing gets saved since `loaded` stays false
+            Err(err) => {
+                let message = format!(
+                    "Your progress couldn't be read, nothing will be saved until this is fixed\n\n{err}"
+                );
+                window().alert_with_message(&message).unwrap_throw();
+                return;
+            }
+        };
+        apply_progress.call(progress);
+        // After the progress, which moves the log out of older saves
+        set_answers.set(load_answers().await);
+    });
+    create_effect(move |_| {
+        let progress = Progress {
+            viewed: viewed.get(),
+            level: level.get(),
+            times_learned: times_learned.get(),
+            last_reviewed: last_reviewed.get(),
+            last_learned: last_learned.get(),
+            synonyms: synonyms.get(),
+            overrides: overrides.get(),
+            choice_weight: choice_weight.get(),
+            session: session.get(),
+        };
+        // Nothing to save until what is on disk has been loaded
+        if !loaded.get() {
+            return;
+        }
+        spawn_local(async move {
+            save_progress(progress).await;
+        });
+    });
     set_interval(
         move || {
-            let Some(value) = local_get::<u32>("last_learned".to_string(), &mut String::new())
-            else {
-                return;
-            };
+            let value = last_learned.get_untracked();
             let today = today();
 
             if last_reviewed.get() as u32 != today && last_reviewed.get() != 255 {
@@ -187,10 +281,13 @@ pub fn App() -> impl IntoView {
         },
     );
 
+    // Loaded once, the artwork doesn't change while the app runs
+    let radical_images = create_resource(|| (), |_| get_radical_images());
+
     let has_acess = create_resource(
-        move || (viewed.get(), level.get()),
-        move |(viewed, level)| async move {
-            if level == 0 {
+        move || (viewed.get(), level.get(), levels_info.get()),
+        move |(viewed, level, levels_info)| async move {
+            if levels_info.starts_pack(level) {
                 return true;
             }
             let Some(kanjis) = get_kanjis(level).await else {
@@ -199,12 +296,22 @@ pub fn App() -> impl IntoView {
             has_acess_logic(level as usize, &Some(kanjis), &viewed).has_all()
         },
     );
-    provide_context((times_learned, set_times_learned));
-    provide_context((last_reviewed, set_last_reviewed));
-    provide_context(level);
-    provide_context((viewed, set_viewed));
+    provide_context((Signal::from(times_learned), set_times_learned));
+    provide_context((Signal::from(last_reviewed), set_last_reviewed));
+    provide_context(LastLearnedContext(last_learned.into(), set_last_learned));
+    provide_context(OverridesContext(overrides.into(), set_overrides));
+    provide_context(ChoiceWeightContext(choice_weight.into(), set_choice_weight));
+    provide_context(Signal::from(level));
+    provide_context(Signal::from(levels_info));
+    provide_context((Signal::from(viewed), set_viewed));
+    provide_context((Signal::from(synonyms), set_synonyms));
+    provide_context((Signal::from(session), set_session));
+    provide_context((Signal::from(answers), set_answers));
+    provide_context(leeches);
     provide_context(kanjis);
     provide_context(has_acess);
+    provide_context(radical_images);
+    provide_context(apply_progress);
     create_effect(move |_| {
         logging::log!("{}", location.pathname.get());
     });
@@ -226,6 +333,12 @@ pub fn App() -> impl IntoView {
                 <A class="navigation" href="/">
                     <svg xmlns="http://www.w3.org/2000/svg"  viewBox="0 0 48 48" width="48px" height="48px"><path d="M39.5,43h-9c-1.381,0-2.5-1.119-2.5-2.5v-9c0-1.105-0.895-2-2-2h-4c-1.105,0-2,0.895-2,2v9c0,1.381-1.119,2.5-2.5,2.5h-9	C7.119,43,6,41.881,6,40.5V21.413c0-2.299,1.054-4.471,2.859-5.893L23.071,4.321c0.545-0.428,1.313-0.428,1.857,0L39.142,15.52	C40.947,16.942,42,19.113,42,21.411V40.5C42,41.881,40.881,43,39.5,43z"/></svg>
                 </A>
+                <A class="navigation" href="/search">
+                    <svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" width="24px" height="24px"><path d="M10 2a8 8 0 0 1 6.32 12.9l5.39 5.4a1 1 0 0 1-1.42 1.4l-5.39-5.38A8 8 0 1 1 10 2zm0 2a6 6 0 1 0 0 12 6 6 0 0 0 0-12z"/></svg>
+                </A>
+                <A class="navigation" href="/stats">
+                    <svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" width="24px" height="24px"><path d="M4 20h16a1 1 0 0 1 0 2H4a2 2 0 0 1-2-2V3a1 1 0 0 1 2 0zm3-3a1 1 0 0 1-1-1v-5a1 1 0 0 1 2 0v5a1 1 0 0 1-1 1zm5 0a1 1 0 0 1-1-1V7a1 1 0 0 1 2 0v9a1 1 0 0 1-1 1zm5 0a1 1 0 0 1-1-1v-7a1 1 0 0 1 2 0v7a1 1 0 0 1-1 1z"/></svg>
+                </A>
                 <span>
                     {move || (LIMIT - times_learned.get())}
                 </span>
@@ -239,11 +352,11 @@ pub fn App() -> impl IntoView {
                     _ => {}
                 }
             }>
-                {(0..60).map(move |a| {
+                {move || levels_info.with(|info| (0..info.count).map(move |a| {
                     view! {
-                        <option value=format!("{a}") selected=move || level.get() == a >{a + 1}</option>
+                        <option value=format!("{a}") selected=move || level.get() == a >{info.level_name(a)}</option>
                     }
-                }).collect_view()}
+                }).collect_view())}
             </select>
         </header>
         <main class="container">
@@ -253,6 +366,8 @@ pub fn App() -> impl IntoView {
                 <Route path="/kanji/:kanji" view=KanjiInfo/>
                 <Route path="/radical/:radical_meaning" view=RadicalInfo />
                 <Route path="/vocab/:vocab" view=VocabInfo/>
+                <Route path="/search" view=Search/>
+                <Route path="/stats" view=Stats/>
             </Routes>
         </main>
     }
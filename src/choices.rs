@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use leptos::{Signal, WriteSignal};
+use rand::{seq::SliceRandom, Rng};
+use serde::{Deserialize, Serialize};
+use wana_kana::ConvertJapanese;
+
+use crate::{invoke::invokers, kanji_info::dislice};
+
+/// Options shown for a multiple choice question, the answer included
+pub const CHOICES: usize = 4;
+
+/// How much a multiple choice answer counts, in percent of a typed one
+#[derive(Clone, Copy)]
+pub struct ChoiceWeightContext(pub Signal<u32>, pub WriteSignal<u32>);
+
+/// A kanji sharing a radical with another one
+#[derive(Clone, PartialEq, Eq, Deserialize, Serialize, Debug)]
+pub struct Neighbour {
+    pub character: String,
+    pub meanings: Vec<String>,
+    pub readings: Vec<String>,
+}
+
+pub async fn get_radical_neighbours(kanjis: Vec<String>) -> HashMap<String, Vec<Neighbour>> {
+    #[derive(Serialize)]
+    struct T {
+        kanjis: Vec<String>,
+    }
+    invokers("get_radical_neighbours", T { kanjis })
+        .await
+        .unwrap_or_default()
+}
+
+/// The main value of a list, readings marked with `!` are the less used ones
+pub fn primary(values: &[String]) -> Option<String> {
+    values
+        .iter()
+        .find(|a| !a.starts_with('!'))
+        .or(values.first())
+        .map(dislice)
+}
+
+/// Readings starting with the same kana or of the same length are easy to mix up
+pub fn similar_reading(a: &str, b: &str) -> bool {
+    let (a, b) = (a.to_hiragana(), b.to_hiragana());
+    a != b && (a.chars().next() == b.chars().next() || a.chars().count() == b.chars().count())
+}
+
+fn same_answer(a: &str, b: &str) -> bool {
+    let (a, b) = (dislice(&a.to_string()), dislice(&b.to_string()));
+    a.to_lowercase() == b.to_lowercase() || a.to_hiragana() == b.to_hiragana()
+}
+
+pub fn is_answer(answers: &[String], choice: &str) -> bool {
+    answers.iter().any(|a| same_answer(a, choice))
+}
+
+/// Up to `CHOICES - 1` distractors, groups are tried in order and shuffled inside.
+/// Nothing that could also be a right answer is picked
+pub fn distractors(
+    groups: Vec<Vec<String>>,
+    answers: &[String],
+    rng: &mut impl Rng,
+) -> Vec<String> {
+    let mut picked: Vec<String> = Vec::new();
+    for mut group in groups {
+        group.shuffle(rng);
+        for value in group {
+            if picked.len() == CHOICES - 1 {
+                return picked;
+            }
+            let value = dislice(&value);
+            if value.is_empty()
+                || is_answer(answers, &value)
+                || picked.iter().any(|a| same_answer(a, &value))
+            {
+                continue;
+            }
+            picked.push(value);
+        }
+    }
+    picked
+}
@@ -10,7 +10,17 @@ pub const UPPER_RADICAL: usize = 5;
 pub const UPPER_KANJI: usize = 15;
 pub const UPPER_VOCABULARY: usize = 10;
 
-use crate::{svgs::Svgs, today, LastReviewedContext, Viewed, ViewedContext};
+use crate::{
+    anki_export::AnkiExport,
+    choices::ChoiceWeightContext,
+    learningkanji::QuestionOrder,
+    leeches::{LeechBadge, LeechesContext},
+    progress::ProgressTransfer,
+    radical_image::RadicalImage,
+    srs::Srs,
+    strokes::has_strokes,
+    today, LastReviewedContext, Viewed, ViewedContext,
+};
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 pub struct Kanji {
@@ -53,21 +63,24 @@ impl std::fmt::Display for File {
 pub fn Home() -> impl IntoView {
     let level = use_context::<Signal<usize>>().expect_throw("failed to context");
     let kanjis = use_context::<Resource<usize, File>>().expect_throw("failed to kanjis resource");
+    // Out here so changing level keeps it
+    let order = create_rw_signal(String::from(QuestionOrder::default().value()));
+    let choice = create_rw_signal(false);
 
     view! {
         {move || { view! {
-            <Container level=level.get() kanjis/>
+            <Container level=level.get() kanjis order choice/>
         }}}
     }
 }
 #[component]
 pub fn ShowRadical(radical: Radical) -> impl IntoView {
     match radical.character.as_str() {
-        "image" => view! {<Svgs  value=radical.meaning.to_lowercase()/>}.into_view(),
+        "image" => view! {<RadicalImage meaning=radical.meaning/>}.into_view(),
         _ => radical.character.into_view(),
     }
 }
-#[derive(PartialEq, Eq, Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub enum MasteryType {
     Kanji,
     Vocabulary,
@@ -78,34 +91,42 @@ fn get_mastery(r#type: MasteryType, identifier: String, viewed: &Viewed, level:
     viewed
         .levels
         .get(level)
-        .map(move |viewed| match r#type {
-            MasteryType::Kanji => viewed
-                .kanjis
+        .and_then(move |viewed| {
+            viewed
+                .of_type(r#type)
                 .iter()
                 .find(|a| a.0 == identifier)
-                .map(|a| a.1)
-                .unwrap_or(0),
-            MasteryType::Radical => viewed
-                .radicals
-                .iter()
-                .find(|a| a.0 == identifier)
-                .map(|a| a.1)
-                .unwrap_or(0),
-            MasteryType::Vocabulary => viewed
-                .vocabs
-                .iter()
-                .find(|a| a.0 == identifier)
-                .map(|a| a.1)
-                .unwrap_or(0),
+                .map(|a| a.1.stage)
         })
         .unwrap_or_default()
 }
 
 #[component]
-fn Container(level: usize, kanjis: Resource<usize, File>) -> impl IntoView {
+fn Container(
+    level: usize,
+    kanjis: Resource<usize, File>,
+    /// Value of the `order` query parameter for the reviews
+    order: RwSignal<String>,
+    /// Reviews are multiple choice instead of typed
+    choice: RwSignal<bool>,
+) -> impl IntoView {
     let (viewed, set_viewed) = use_context::<ViewedContext>().expect_throw("expected view context");
     let (last_reviewed, _) =
         use_context::<LastReviewedContext>().expect_throw("last reviewed context");
+    let ChoiceWeightContext(choice_weight, set_choice_weight) =
+        use_context::<ChoiceWeightContext>().expect_throw("choice weight context");
+    let leeches = use_context::<LeechesContext>().expect_throw("leeches context");
+    // The writing review would be empty without it
+    let strokes_loaded = create_resource(|| (), |_| has_strokes());
+    let review_href = move |kinds: &'static str| {
+        move || {
+            format!(
+                "/learningkanji?{kinds}&order={}&choice={}",
+                order.get(),
+                choice.get()
+            )
+        }
+    };
     let current_view = move || viewed.get().levels.get(level).cloned().unwrap_or_default();
     let has_acess =
         use_context::<Resource<(Viewed, usize), bool>>().expect_throw("cant get acess context");
@@ -122,25 +143,31 @@ fn Container(level: usize, kanjis: Resource<usize, File>) -> impl IntoView {
                 for i in &kanjis.radicals {
                     let pog = viewed.radicals.iter_mut().find(|a| a.0 == i.meaning);
                     if let Some(pog) = pog {
-                        pog.1 = UPPER_RADICAL;
+                        pog.1.stage = UPPER_RADICAL;
                     } else {
-                        viewed.radicals.push((i.meaning.clone(), UPPER_RADICAL));
+                        viewed
+                            .radicals
+                            .push((i.meaning.clone(), Srs::with_stage(UPPER_RADICAL)));
                     }
                 }
                 for i in &kanjis.kanjis {
                     let pog = viewed.kanjis.iter_mut().find(|a| a.0 == i.character);
                     if let Some(pog) = pog {
-                        pog.1 = UPPER_KANJI;
+                        pog.1.stage = UPPER_KANJI;
                     } else {
-                        viewed.kanjis.push((i.character.clone(), UPPER_KANJI));
+                        viewed
+                            .kanjis
+                            .push((i.character.clone(), Srs::with_stage(UPPER_KANJI)));
                     }
                 }
                 for i in &kanjis.vocabs {
                     let pog = viewed.vocabs.iter_mut().find(|a| a.0 == i.character);
                     if let Some(pog) = pog {
-                        pog.1 = UPPER_VOCABULARY;
+                        pog.1.stage = UPPER_VOCABULARY;
                     } else {
-                        viewed.vocabs.push((i.character.clone(), UPPER_VOCABULARY));
+                        viewed
+                            .vocabs
+                            .push((i.character.clone(), Srs::with_stage(UPPER_VOCABULARY)));
                     }
                 }
                 Some(viewed)
@@ -168,18 +195,59 @@ fn Container(level: usize, kanjis: Resource<usize, File>) -> impl IntoView {
             } else {
                 view! {
                     <section class="reviews">
-                        <A class="button" href="/learningkanji?kanji=true&vocab=true&radical=true">
+                        <select class="order" on:change=move |ev| order.set(event_target_value(&ev))>
+                            {QuestionOrder::ALL.into_iter().map(|a| view! {
+                                <option value=a.value() selected=move || order.get() == a.value()>
+                                    {a.label()}
+                                </option>
+                            }).collect_view()}
+                        </select>
+                        <label class="choice">
+                            <input
+                                type="checkbox"
+                                prop:checked=move || choice.get()
+                                on:change=move |ev| choice.set(event_target_checked(&ev))
+                            />
+                            Multiple choice
+                        </label>
+                        {move || choice.get().then(|| view! {
+                            <label class="choice" title="How much a picked answer counts next to a typed one">
+                                counts for
+                                <input
+                                    type="number"
+                                    min="0"
+                                    max="100"
+                                    prop:value=move || choice_weight.get()
+                                    on:change=move |ev| if let Ok(weight) = event_target_value(&ev).parse::<u32>() {
+                                        set_choice_weight.set(weight.min(100));
+                                    }
+                                />
+                                "%"
+                            </label>
+                        })}
+                        <A class="button" href=review_href("kanji=true&vocab=true&radical=true")>
                             Full review
                         </A>
-                        <A class="button radical" href="/learningkanji?kanji=false&vocab=false&radical=true">
+                        <A class="button" href=review_href("kanji=true&vocab=true&radical=true&all=true")>
+                            Review everything due
+                        </A>
+                        <A class="button radical" href=review_href("kanji=false&vocab=false&radical=true")>
                             Radical review
                         </A>
-                        <A class="button kanji" href="/learningkanji?kanji=true&vocab=false&radical=false">
+                        <A class="button kanji" href=review_href("kanji=true&vocab=false&radical=false")>
                             Kanji review
                         </A>
-                        <A class="button vocab" href="/learningkanji?kanji=false&vocab=true&radical=false">
+                        <A class="button vocab" href=review_href("kanji=false&vocab=true&radical=false")>
                             Vocabulary review
                         </A>
+                        <A class="button vocab" href=review_href("kanji=false&vocab=false&radical=false&reverse=true")>
+                            Write the vocabulary
+                        </A>
+                        {move || strokes_loaded.get().unwrap_or_default().then(|| view! {
+                            <A class="button kanji" href=review_href("kanji=false&vocab=false&radical=false&writing=true")>
+                                Draw the kanji
+                            </A>
+                        })}
                     </section>
 
                 }
@@ -187,6 +255,14 @@ fn Container(level: usize, kanjis: Resource<usize, File>) -> impl IntoView {
 
         })}
 
+        // Practice that doesn't touch the schedule, so it is there after the reviews too
+        {move || leeches.with(|leeches| (!leeches.is_empty()).then(|| view! {
+            <section class="reviews">
+                <A class="button leech" href=review_href("kanji=true&vocab=true&radical=true&all=true&leech=true")>
+                    "Leech drill (" {leeches.len()} ")"
+                </A>
+            </section>
+        }))}
 
         // <button on:click=clear_level>
         //     clear level
@@ -200,6 +276,7 @@ fn Container(level: usize, kanjis: Resource<usize, File>) -> impl IntoView {
                     let Radical { meaning, ..} = radical.clone();
                     let meaning_2 = meaning.clone();
                     let meaning_3 = meaning.clone();
+                    let meaning_4 = meaning.clone();
                     let is_radical_pog = current_view_radicals.clone().into_iter().find(move |a| a.0 == meaning_2.clone()).is_some();
                     let mastery = viewed.with(move |viewed| get_mastery(MasteryType::Radical, meaning_3.clone(),viewed, level));
                     view! {
@@ -207,6 +284,7 @@ fn Container(level: usize, kanjis: Resource<usize, File>) -> impl IntoView {
                             <div>
                                 <ShowRadical radical=radical.clone() />
                             </div>
+                            <LeechBadge kind=MasteryType::Radical level identifier=meaning_4 />
                             <div
                                 style=format!("width: {}%;", ((100 as f32/ UPPER_RADICAL as f32)*mastery as f32).min(100.0))
                                 >
@@ -233,6 +311,7 @@ fn Container(level: usize, kanjis: Resource<usize, File>) -> impl IntoView {
                             <div>
                                 {character.clone()}
                             </div>
+                            <LeechBadge kind=MasteryType::Kanji level identifier=character.clone() />
                             <div
                                 style=format!("width: {}%;", ((100 as f32/ UPPER_KANJI as f32)*mastery as f32).min(100.0))
                             />
@@ -257,6 +336,7 @@ fn Container(level: usize, kanjis: Resource<usize, File>) -> impl IntoView {
                             <span>
                                 {character.clone()}
                             </span>
+                            <LeechBadge kind=MasteryType::Vocabulary level identifier=character.clone() />
                             <div>
                                 <span>
                                     {meaning.clone()}
@@ -279,5 +359,7 @@ fn Container(level: usize, kanjis: Resource<usize, File>) -> impl IntoView {
                 Clear this level
             </button>
         </section>
+        <ProgressTransfer />
+        <AnkiExport />
     }
 }
@@ -18,6 +18,19 @@ where
     let res = invoke(cmd, value).await.unwrap_throw();
     serde_wasm_bindgen::from_value(res)
 }
+/// For commands returning a `Result`, the error comes back as its message
+/// instead of throwing
+pub async fn invoke_result<T, A>(cmd: &str, value: T) -> Result<A, String>
+where
+    T: serde::Serialize + Sized,
+    A: DeserializeOwned,
+{
+    let value = serde_wasm_bindgen::to_value(&value).map_err(|err| err.to_string())?;
+    let res = invoke(cmd, value)
+        .await
+        .map_err(|err| err.as_string().unwrap_or_else(|| format!("{err:?}")))?;
+    serde_wasm_bindgen::from_value(res).map_err(|err| err.to_string())
+}
 pub async fn get_kanjis(level: usize) -> Option<File> {
     #[derive(Serialize)]
     struct T {
@@ -25,3 +38,17 @@ pub async fn get_kanjis(level: usize) -> Option<File> {
     }
     invokers("get_kanjis", T { level }).await.ok()
 }
+/// Files for every level asked, levels start at 0 on both sides
+pub async fn get_kanjis_range(levels: Vec<usize>) -> Option<Vec<(usize, File)>> {
+    #[derive(Serialize)]
+    struct T {
+        levels: Vec<usize>,
+    }
+    let levels = levels.into_iter().map(|level| level + 1).collect();
+    let res: Vec<(usize, File)> = invokers("get_kanjis_range", T { levels }).await.ok()?;
+    Some(
+        res.into_iter()
+            .map(|(level, file)| (level - 1, file))
+            .collect(),
+    )
+}
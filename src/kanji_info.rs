@@ -1,14 +1,20 @@
 use crate::button_link::ButtonLink;
 use crate::has_acess_logic;
+use crate::home::MasteryType;
 use crate::invoke::get_kanjis;
 use crate::invoke::invokers;
-use crate::utils::local_set;
+use crate::packs::LevelsInfo;
+use crate::srs::Srs;
+use crate::strokes::StrokeOrder;
+use crate::synonyms::SynonymsEditor;
+use crate::LastLearnedContext;
 use crate::ViewedContext;
 use crate::LIMIT;
 use chrono::Datelike;
 use leptos::*;
 use leptos_router::use_params;
 use leptos_router::Params;
+use leptos_router::A;
 use serde::Deserialize;
 use serde::Serialize;
 use wana_kana::ConvertJapanese;
@@ -38,6 +44,15 @@ struct KanjiInfo {
     pub wk_level: Option<u32>,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+struct VocabSummary {
+    character: String,
+    reading: String,
+    meaning: String,
+    /// Counting from 0
+    level: Option<usize>,
+}
+
 #[component]
 pub fn KanjiInfo() -> impl IntoView {
     // let kanjis = use_context::<Resource<usize, File>>().expect_throw("kanjis resources");
@@ -46,6 +61,9 @@ pub fn KanjiInfo() -> impl IntoView {
     let params = use_params::<KanjiParams>();
     let (times_learned, set_times_learned) =
         use_context::<(Signal<u32>, WriteSignal<u32>)>().expect_throw("times learned context");
+    let LastLearnedContext(_, set_last_learned) =
+        use_context::<LastLearnedContext>().expect_throw("last learned context");
+    let levels_info = use_context::<Signal<LevelsInfo>>().expect_throw("levels info context");
     let character = move || match params.get() {
         Ok(a) => a.kanji,
         _ => None,
@@ -71,6 +89,31 @@ pub fn KanjiInfo() -> impl IntoView {
         },
     );
 
+    let vocabs = create_resource(
+        move || character(),
+        move |a| async move {
+            let Some(kanji) = a else {
+                return Vec::new();
+            };
+            #[derive(Serialize)]
+            struct T {
+                kanji: String,
+            }
+            invokers::<T, Vec<VocabSummary>>("get_kanji_vocabs", T { kanji })
+                .await
+                .unwrap_or_default()
+        },
+    );
+    let is_learned = move |vocab: &VocabSummary| {
+        viewed.with(|viewed| {
+            vocab
+                .level
+                .and_then(|a| viewed.levels.get(a))
+                .map(|a| a.vocabs.iter().any(|a| a.0 == vocab.character))
+                .unwrap_or_default()
+        })
+    };
+
     let has_learned = create_memo(move |_| {
         with!(move |viewed, info| {
             let level = match info {
@@ -89,17 +132,17 @@ pub fn KanjiInfo() -> impl IntoView {
     });
 
     let has_acess = create_resource(
-        move || (viewed.get(), info.get()),
-        move |(viewed, info)| async move {
+        move || (viewed.get(), info.get(), levels_info.get()),
+        move |(viewed, info, levels_info)| async move {
             let level = match info {
                 Some(Some(a)) if a.wk_level.is_some() => a.wk_level.unwrap() - 1,
                 _ => return false,
             };
-            if level == 0 {
-                let Some(kanjis) = get_kanjis(1).await else {
+            if levels_info.starts_pack(level as usize) {
+                let Some(kanjis) = get_kanjis(level as usize + 1).await else {
                     return false;
                 };
-                has_acess_logic(1, &Some(kanjis), &viewed)
+                has_acess_logic(level as usize + 1, &Some(kanjis), &viewed)
                     .radical
                     .partial_or_more()
             } else {
@@ -148,11 +191,11 @@ pub fn KanjiInfo() -> impl IntoView {
                 return;
             }
             let now = chrono::offset::Local::now();
-            local_set("last_learned".to_string(), now.date_naive().day());
+            set_last_learned.set(now.date_naive().day());
             set_times_learned.update(|a| {
                 *a = *a + 1;
             });
-            level_viewed.kanjis.push((character, 0));
+            level_viewed.kanjis.push((character, Srs::default()));
         })
     };
     let info = move || info.get().flatten();
@@ -228,6 +271,33 @@ pub fn KanjiInfo() -> impl IntoView {
                 }
             })}
 
+            <StrokeOrder kanji=Signal::derive(move || character().unwrap_or_default()) />
+            {move || vocabs.get().filter(|a| !a.is_empty()).map(|vocabs| view! {
+                <section class="used_in vocab">
+                    <h3>
+                        Vocabulary
+                    </h3>
+                    <div>
+                        {vocabs.into_iter().map(|vocab| {
+                            let learned = is_learned(&vocab);
+                            view! {
+                            <A class=move || learned.then(|| "viewed").unwrap_or_default() href=format!("/vocab/{}", vocab.character)>
+                                <span class="character"> {vocab.character.clone()} </span>
+                                <span> {vocab.reading.clone()} </span>
+                                <span> {vocab.meaning.clone()} </span>
+                                {vocab.level.map(|a| view! {
+                                    <span class="level"> Level {levels_info.with(|info| info.level_name(a))} </span>
+                                })}
+                            </A>
+                            }
+                        }).collect_view()}
+                    </div>
+                </section>
+            })}
+            <SynonymsEditor
+                kind=MasteryType::Kanji
+                identifier=Signal::derive(move || character().unwrap_or_default()) readings=true
+            />
             {move || character().map(|character| view! {
                 <section class="other_sources">
                     <h3>
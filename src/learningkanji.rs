@@ -1,26 +1,68 @@
-use std::time::Duration;
+use std::{collections::HashMap, time::Duration};
 
 use crate::{
-    home::{File, Radical, ShowRadical},
-    invoke::invokers,
+    choices::{
+        distractors, get_radical_neighbours, is_answer, primary, similar_reading,
+        ChoiceWeightContext, Neighbour, CHOICES,
+    },
+    home::{File, MasteryType, Radical, ShowRadical},
+    invoke::{get_kanjis_range, invokers},
     kanji_info::dislice,
+    leeches::{find_leech, Leech, LeechesContext},
+    readings::near_miss,
+    srs,
+    stats::{log_answers, Answer, AnswersContext, Outcome},
+    strokes::get_strokes_range,
+    synonyms::{Synonyms, SynonymsContext, SynonymsEditor},
     today,
     vocab_info::VocabFullInfo,
-    LastReviewedContext, Viewed, ViewedContext, ViewedList,
+    writing::WritingPad,
+    LastReviewedContext, OverridesContext, Viewed, ViewedContext, ViewedList,
 };
 use leptos::*;
 use leptos_router::{use_navigate, use_query, NavigateOptions, Params};
-use rand::Rng;
+use rand::{rngs::StdRng, Rng, SeedableRng};
 use rust_fuzzy_search::fuzzy_compare;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use wana_kana::{ConvertJapanese, IsJapaneseStr};
 use wasm_bindgen::prelude::*;
 use web_sys::SubmitEvent;
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 struct QuestionStatus {
     question_type: QuestionType,
     is_correct: bool,
+    /// Marked wrong at first and counted as correct by the user
+    overridden: bool,
+    /// Answered by picking one of the choices
+    #[serde(default)]
+    multiple_choice: bool,
     identifier: String,
+    level: usize,
+    /// Unix timestamp in seconds, 0 in sessions saved before it was kept
+    #[serde(default)]
+    answered_at: i64,
+    #[serde(default)]
+    bit_off: bool,
+}
+impl QuestionStatus {
+    /// What goes in the answer log, `now` stands in for a missing time
+    fn logged(&self, now: i64) -> Answer {
+        Answer {
+            time: match self.answered_at {
+                0 => now,
+                time => time,
+            },
+            level: self.level,
+            identifier: self.identifier.clone(),
+            question_type: self.question_type,
+            outcome: match (self.is_correct, self.bit_off) {
+                (true, true) => Outcome::CorrectBitOff,
+                (true, false) => Outcome::Correct,
+                (false, _) => Outcome::HardError,
+            },
+            overridden: self.overridden,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -41,7 +83,7 @@ impl AnswerStatus {
         *self == Self::HardError
     }
 }
-#[derive(PartialEq, Eq, Clone, Debug, Copy)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Debug, Copy, Serialize, Deserialize)]
 pub enum QuestionType {
     VocabularyReading,
     VocabularyMeaning,
@@ -49,6 +91,12 @@ pub enum QuestionType {
     KanjiReadingKun,
     KanjiMeaning,
     Radical,
+    /// Shows the meaning and asks for the word written in japanese
+    VocabularyFromMeaning,
+    /// Shows the reading and asks for the word written in japanese
+    VocabularyFromReading,
+    /// Shows the meaning and the kanji is drawn stroke by stroke
+    KanjiWriting,
 }
 
 impl QuestionType {
@@ -60,7 +108,10 @@ impl QuestionType {
     }
     fn is_kanji(&self) -> bool {
         match self {
-            Self::KanjiMeaning | Self::KanjiReadingKun | Self::KanjiReadingOn => true,
+            Self::KanjiMeaning
+            | Self::KanjiReadingKun
+            | Self::KanjiReadingOn
+            | Self::KanjiWriting => true,
             _ => false,
         }
     }
@@ -69,32 +120,149 @@ impl QuestionType {
     }
     fn is_vocab(&self) -> bool {
         match self {
-            Self::VocabularyMeaning | Self::VocabularyReading => true,
+            Self::VocabularyMeaning
+            | Self::VocabularyReading
+            | Self::VocabularyFromMeaning
+            | Self::VocabularyFromReading => true,
+            _ => false,
+        }
+    }
+    fn is_written(&self) -> bool {
+        match self {
+            Self::VocabularyFromMeaning | Self::VocabularyFromReading => true,
             _ => false,
         }
     }
+    pub fn mastery_type(&self) -> MasteryType {
+        match self {
+            Self::KanjiMeaning
+            | Self::KanjiReadingKun
+            | Self::KanjiReadingOn
+            | Self::KanjiWriting => MasteryType::Kanji,
+            Self::VocabularyMeaning
+            | Self::VocabularyReading
+            | Self::VocabularyFromMeaning
+            | Self::VocabularyFromReading => MasteryType::Vocabulary,
+            Self::Radical => MasteryType::Radical,
+        }
+    }
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::VocabularyReading => "Vocabulary reading",
+            Self::VocabularyMeaning => "Vocabulary meaning",
+            Self::KanjiReadingOn => "Kanji onyoumi",
+            Self::KanjiReadingKun => "Kanji kunyoumi",
+            Self::KanjiMeaning => "Kanji meaning",
+            Self::Radical => "Radical",
+            Self::VocabularyFromMeaning => "Vocabulary from the meaning",
+            Self::VocabularyFromReading => "Vocabulary from the reading",
+            Self::KanjiWriting => "Drawing the kanji",
+        }
+    }
 }
-#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 pub struct Question {
     answers: Vec<String>,
+    /// Added by the user, only accepted when exact
+    synonyms: Vec<String>,
     question_type: QuestionType,
     alert: Vec<String>,
     alert_kana: Vec<String>,
     question: String,
     identifier: String,
+    /// Shown instead of the identifier when that is the answer
+    #[serde(default)]
+    prompt: Option<String>,
+    /// Options to pick from instead of typing, empty when typing
+    #[serde(default)]
+    choices: Vec<String>,
+    /// Paths of the strokes when the kanji has to be drawn
+    #[serde(default)]
+    strokes: Vec<String>,
     radical_character: Option<String>,
+    level: usize,
+}
+/// A review that is not finished yet, saved after every answer
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct Session {
+    /// What is left, starting with the question on screen
+    queue: Vec<Question>,
+    results: Vec<QuestionStatus>,
+    /// What the queue was shuffled with
+    seed: u64,
+    /// Leech practice, the answers don't change the mastery
+    #[serde(default)]
+    drill: bool,
+}
+pub type SessionContext = (Signal<Option<Session>>, WriteSignal<Option<Session>>);
+
+#[derive(Clone)]
+enum Resume {
+    Ask,
+    Resume(Session),
+    New,
 }
+
 #[derive(Params, PartialEq, Eq, Default)]
 struct IncludeQuery {
     kanji: Option<bool>,
     vocab: Option<bool>,
     radical: Option<bool>,
+    all: Option<bool>,
+    order: Option<String>,
+    /// Adds questions asking for the written form of the vocabulary
+    reverse: Option<bool>,
+    /// Picking from a few options instead of typing
+    choice: Option<bool>,
+    /// Drawing the kanji from their meaning
+    writing: Option<bool>,
+    /// Only the leeches, due or not
+    leech: Option<bool>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum QuestionOrder {
+    #[default]
+    Random,
+    /// Every question of an item back to back
+    Paired,
+    /// Radicals, then kanji, then vocabulary
+    ByType,
+    /// Least mastered items first
+    Mastery,
+}
+impl QuestionOrder {
+    pub const ALL: [Self; 4] = [Self::Random, Self::Paired, Self::ByType, Self::Mastery];
+    /// What goes in the `order` query parameter
+    pub fn value(&self) -> &'static str {
+        match self {
+            Self::Random => "random",
+            Self::Paired => "paired",
+            Self::ByType => "type",
+            Self::Mastery => "mastery",
+        }
+    }
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Random => "Random order",
+            Self::Paired => "Meaning and reading together",
+            Self::ByType => "Radicals, then kanji, then vocabulary",
+            Self::Mastery => "Least mastered first",
+        }
+    }
+    fn from_value(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|a| a.value() == value)
+    }
 }
 #[derive(Default)]
 struct ShouldInclude {
     kanji: bool,
     radical: bool,
     vocab: bool,
+    reverse: bool,
+    writing: bool,
+    /// Asks these items instead of the ones that are due
+    leeches: Option<Vec<Leech>>,
 }
 #[wasm_bindgen]
 extern "C" {
@@ -102,71 +270,116 @@ extern "C" {
     async fn invoke(cmd: &str, args: JsValue) -> JsValue;
 }
 
+/// `choice_weight` is how much a picked answer counts, in percent of a typed one
 fn add_mastery(
     question_status: Vec<QuestionStatus>,
     viewed: &Viewed,
-    level: usize,
-) -> Option<ViewedList> {
-    let mut mastery = viewed.levels.get(level)?.clone();
+    choice_weight: u32,
+) -> Viewed {
+    let mut mastery = viewed.clone();
+    // (level, type, identifier, correct, missed) for every item in the session,
+    // correct answers are counted in percent
+    let mut results: Vec<(usize, MasteryType, String, usize, usize)> = Vec::new();
     for question in question_status {
-        if question.is_correct {
-            match question.question_type {
-                QuestionType::KanjiMeaning
-                | QuestionType::KanjiReadingOn
-                | QuestionType::KanjiReadingKun => {
-                    let Some(kanji) = mastery
-                        .kanjis
-                        .iter_mut()
-                        .find(|a| a.0 == question.identifier)
-                    else {
-                        continue;
-                    };
-                    kanji.1 += 1;
-                }
-                QuestionType::VocabularyMeaning | QuestionType::VocabularyReading => {
-                    let Some(vocab) = mastery
-                        .vocabs
-                        .iter_mut()
-                        .find(|a| a.0 == question.identifier)
-                    else {
-                        continue;
-                    };
-                    vocab.1 += 1;
-                }
-                QuestionType::Radical => {
-                    let Some(radical) = mastery
-                        .radicals
-                        .iter_mut()
-                        .find(|a| a.0 == question.identifier)
-                    else {
-                        continue;
-                    };
-                    radical.1 += 1;
-                }
+        let r#type = question.question_type.mastery_type();
+        let index = match results
+            .iter()
+            .position(|a| a.0 == question.level && a.1 == r#type && a.2 == question.identifier)
+        {
+            Some(index) => index,
+            None => {
+                results.push((question.level, r#type, question.identifier, 0, 0));
+                results.len() - 1
             }
+        };
+        if question.is_correct {
+            results[index].3 += match question.multiple_choice {
+                true => choice_weight as usize,
+                false => 100,
+            };
+        } else {
+            results[index].4 += 1;
         }
     }
-    Some(mastery)
+    let now = srs::now();
+    for (level, r#type, identifier, correct, missed) in results {
+        let Some(item) = mastery
+            .levels
+            .get_mut(level)
+            .into_iter()
+            .flat_map(|a| a.of_type_mut(r#type).iter_mut())
+            .find(|a| a.0 == identifier)
+        else {
+            continue;
+        };
+        item.1.review(correct, missed, now);
+    }
+    mastery
 }
 
-fn get_question_queue(
+/// Questions for the items of one level that are due
+fn level_questions(
     file: &File,
-    viewed: &Viewed,
-    level: usize,
+    level_index: usize,
+    level: &ViewedList,
     vocabs: &Vec<(String, VocabFullInfo)>,
-    should_include: ShouldInclude,
-) -> Option<Vec<Question>> {
-    let mut queue = Vec::new();
-    let level = viewed.levels.get(level)?;
+    should_include: &ShouldInclude,
+    synonyms: &Synonyms,
+    now: i64,
+) -> Vec<Question> {
     let mut total_questions = Vec::new();
-    if should_include.kanji {
-        for (value, _) in &level.kanjis {
+    let is_asked =
+        |r#type: MasteryType, identifier: &str, srs: &srs::Srs| match &should_include.leeches {
+            Some(leeches) => find_leech(leeches, r#type, level_index, identifier).is_some(),
+            None => srs.is_due(now),
+        };
+    let meanings_of = |r#type: MasteryType, identifier: &String| {
+        synonyms
+            .get(r#type, identifier)
+            .map(|a| a.meanings.clone())
+            .unwrap_or_default()
+    };
+    let readings_of = |r#type: MasteryType, identifier: &String| {
+        synonyms
+            .get(r#type, identifier)
+            .map(|a| a.readings.clone())
+            .unwrap_or_default()
+    };
+    if should_include.kanji || should_include.writing {
+        for (value, srs) in &level.kanjis {
+            if !is_asked(MasteryType::Kanji, value, srs) {
+                continue;
+            }
             let Some(kanji) = file.kanjis.iter().find(|a| &a.character == value) else {
                 continue;
             };
             if kanji.character.is_empty() {
                 continue;
             }
+            if should_include.writing {
+                let meaning = primary(&kanji.meanings).unwrap_or_default();
+                let prompt = match primary(&kanji.readings_on).or(primary(&kanji.readings_kun)) {
+                    Some(reading) => format!("{meaning} ({reading})"),
+                    None => meaning,
+                };
+                total_questions.push(Question {
+                    alert: Vec::new(),
+                    alert_kana: Vec::new(),
+                    answers: vec![kanji.character.clone()],
+                    synonyms: Vec::new(),
+                    question_type: QuestionType::KanjiWriting,
+                    question: String::from("Write the kanji"),
+                    identifier: kanji.character.clone(),
+                    prompt: Some(prompt),
+                    choices: Vec::new(),
+                    strokes: Vec::new(),
+                    radical_character: None,
+                    level: level_index,
+                });
+            }
+            if !should_include.kanji {
+                continue;
+            }
             if !kanji.meanings.is_empty() {
                 let mut alert_kana = Vec::new();
                 alert_kana.extend(kanji.readings_kun.clone());
@@ -176,61 +389,131 @@ fn get_question_queue(
                     alert_kana,
                     alert: Vec::new(),
                     answers: kanji.meanings.clone(),
+                    synonyms: meanings_of(MasteryType::Kanji, &kanji.character),
                     question_type: QuestionType::KanjiMeaning,
                     question: String::from("The meaning"),
                     identifier: kanji.character.clone(),
+                    prompt: None,
+                    choices: Vec::new(),
+                    strokes: Vec::new(),
                     radical_character: None,
+                    level: level_index,
                 });
             }
             if !kanji.readings_kun.is_empty() {
                 total_questions.push(Question {
                     answers: kanji.readings_kun.clone(),
+                    synonyms: readings_of(MasteryType::Kanji, &kanji.character),
                     question_type: QuestionType::KanjiReadingKun,
                     alert: kanji.meanings.clone(),
                     alert_kana: kanji.readings_on.clone(),
                     question: String::from("The kunyoumi reading"),
                     identifier: kanji.character.clone(),
+                    prompt: None,
+                    choices: Vec::new(),
+                    strokes: Vec::new(),
                     radical_character: None,
+                    level: level_index,
                 });
             }
             if !kanji.readings_on.is_empty() {
                 total_questions.push(Question {
                     answers: kanji.readings_on.clone(),
+                    synonyms: readings_of(MasteryType::Kanji, &kanji.character),
                     question_type: QuestionType::KanjiReadingOn,
                     alert: kanji.meanings.clone(),
                     alert_kana: kanji.readings_kun.clone(),
                     question: String::from("The onyoumi reading"),
                     identifier: kanji.character.clone(),
+                    prompt: None,
+                    choices: Vec::new(),
+                    strokes: Vec::new(),
                     radical_character: None,
+                    level: level_index,
                 });
             }
         }
     }
-    if should_include.vocab {
+    if should_include.vocab || should_include.reverse {
         for (character, vocab) in vocabs {
+            let is_asked = level
+                .vocabs
+                .iter()
+                .any(|a| &a.0 == character && is_asked(MasteryType::Vocabulary, &a.0, &a.1));
+            if !is_asked {
+                continue;
+            }
+            if should_include.reverse {
+                let written: Vec<String> = std::iter::once(character.clone())
+                    .chain(vocab.another_form.iter().cloned())
+                    .collect();
+                total_questions.push(Question {
+                    alert: Vec::new(),
+                    alert_kana: vocab.readings.clone(),
+                    answers: written.clone(),
+                    synonyms: Vec::new(),
+                    identifier: character.clone(),
+                    question: String::from("The word with this meaning"),
+                    question_type: QuestionType::VocabularyFromMeaning,
+                    prompt: Some(vocab.primary_meaning.clone()),
+                    choices: Vec::new(),
+                    strokes: Vec::new(),
+                    radical_character: None,
+                    level: level_index,
+                });
+                total_questions.push(Question {
+                    alert: Vec::new(),
+                    alert_kana: vocab.readings.clone(),
+                    answers: written,
+                    synonyms: Vec::new(),
+                    identifier: character.clone(),
+                    question: String::from("The word with this reading"),
+                    question_type: QuestionType::VocabularyFromReading,
+                    prompt: Some(vocab.primary_reading.clone()),
+                    choices: Vec::new(),
+                    strokes: Vec::new(),
+                    radical_character: None,
+                    level: level_index,
+                });
+            }
+            if !should_include.vocab {
+                continue;
+            }
             total_questions.push(Question {
                 alert: vocab.meanings.clone(),
                 alert_kana: Vec::new(),
                 answers: vocab.readings.clone(),
+                synonyms: readings_of(MasteryType::Vocabulary, character),
                 identifier: character.clone(),
                 question: format!("The reading"),
                 question_type: QuestionType::VocabularyReading,
+                prompt: None,
+                choices: Vec::new(),
+                strokes: Vec::new(),
                 radical_character: None,
+                level: level_index,
             });
             total_questions.push(Question {
                 alert: Vec::new(),
                 alert_kana: vocab.readings.clone(),
                 answers: vocab.meanings.clone(),
+                synonyms: meanings_of(MasteryType::Vocabulary, character),
                 identifier: character.clone(),
                 question: format!("The meaning"),
                 question_type: QuestionType::VocabularyMeaning,
+                prompt: None,
+                choices: Vec::new(),
+                strokes: Vec::new(),
                 radical_character: None,
+                level: level_index,
             });
         }
     }
     if should_include.radical {
-        for (value, _) in &level.radicals {
-            logging::log!("{}", value);
+        for (value, srs) in &level.radicals {
+            if !is_asked(MasteryType::Radical, value, srs) {
+                continue;
+            }
             let Some(radical) = file.radicals.iter().find(|a| &a.meaning == value) else {
                 continue;
             };
@@ -241,16 +524,186 @@ fn get_question_queue(
                 alert: Vec::new(),
                 alert_kana: Vec::new(),
                 answers: vec![radical.meaning.to_lowercase()],
+                synonyms: meanings_of(MasteryType::Radical, &radical.meaning),
                 identifier: radical.meaning.clone(),
+                prompt: None,
+                choices: Vec::new(),
+                strokes: Vec::new(),
                 radical_character: Some(radical.character.clone()),
                 question: String::from("Thea name of the radical"),
                 question_type: QuestionType::Radical,
+                level: level_index,
             };
             total_questions.push(question)
         }
     }
+    total_questions
+}
+
+/// Turns every question into a multiple choice one. The distractors come from
+/// kanji sharing a radical, similar readings and the rest of the level
+fn add_choices(
+    queue: &mut [Question],
+    files: &[(usize, File)],
+    neighbours: &HashMap<String, Vec<Neighbour>>,
+    seed: u64,
+) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    for question in queue {
+        let Some((_, file)) = files.iter().find(|a| a.0 == question.level) else {
+            continue;
+        };
+        let Some(correct) = (match question.question_type.is_written() {
+            true => Some(question.identifier.clone()),
+            false => primary(&question.answers),
+        }) else {
+            continue;
+        };
+        let id = &question.identifier;
+        let neighbours = neighbours.get(id).map(|a| a.as_slice()).unwrap_or_default();
+        let other_kanjis = || file.kanjis.iter().filter(|a| &a.character != id);
+        let other_vocabs = || file.vocabs.iter().filter(|a| &a.character != id);
+        let shares_kanji = |word: &String| word.chars().any(|a| id.contains(a));
+        let groups: Vec<Vec<String>> = match question.question_type {
+            QuestionType::KanjiMeaning => vec![
+                neighbours
+                    .iter()
+                    .flat_map(|a| primary(&a.meanings))
+                    .collect(),
+                other_kanjis().flat_map(|a| primary(&a.meanings)).collect(),
+            ],
+            QuestionType::KanjiReadingOn | QuestionType::KanjiReadingKun => {
+                let level_readings: Vec<String> = other_kanjis()
+                    .flat_map(|a| a.readings_on.iter().chain(a.readings_kun.iter()))
+                    .cloned()
+                    .collect();
+                vec![
+                    level_readings
+                        .iter()
+                        .filter(|a| similar_reading(a, &correct))
+                        .cloned()
+                        .collect(),
+                    neighbours
+                        .iter()
+                        .flat_map(|a| a.readings.iter())
+                        .filter(|a| similar_reading(a, &correct))
+                        .cloned()
+                        .collect(),
+                    level_readings,
+                ]
+            }
+            QuestionType::VocabularyReading => vec![
+                other_vocabs()
+                    .filter(|a| similar_reading(&a.reading, &correct))
+                    .map(|a| a.reading.clone())
+                    .collect(),
+                other_vocabs().map(|a| a.reading.clone()).collect(),
+            ],
+            QuestionType::VocabularyMeaning => vec![
+                other_vocabs()
+                    .filter(|a| shares_kanji(&a.character))
+                    .map(|a| a.meaning.clone())
+                    .collect(),
+                other_vocabs().map(|a| a.meaning.clone()).collect(),
+            ],
+            QuestionType::VocabularyFromMeaning | QuestionType::VocabularyFromReading => {
+                let reading = file
+                    .vocabs
+                    .iter()
+                    .find(|a| &a.character == id)
+                    .map(|a| a.reading.clone())
+                    .unwrap_or_default();
+                vec![
+                    other_vocabs()
+                        .filter(|a| shares_kanji(&a.character))
+                        .map(|a| a.character.clone())
+                        .collect(),
+                    other_vocabs()
+                        .filter(|a| similar_reading(&a.reading, &reading))
+                        .map(|a| a.character.clone())
+                        .collect(),
+                    other_vocabs().map(|a| a.character.clone()).collect(),
+                ]
+            }
+            QuestionType::KanjiWriting => continue,
+            QuestionType::Radical => vec![file
+                .radicals
+                .iter()
+                .filter(|a| &a.meaning != id)
+                .map(|a| a.meaning.clone())
+                .collect()],
+        };
+        let answers = match question.question_type.is_written() {
+            true => vec![correct.clone()],
+            false => question.answers.clone(),
+        };
+        let mut choices = distractors(groups, &answers, &mut rng);
+        if choices.is_empty() {
+            continue;
+        }
+        choices.insert(rng.gen_range(0..=choices.len()), correct);
+        // Capitalized distractors next to a lowercase answer would give it away
+        if !question.question_type.is_kana() && !question.question_type.is_written() {
+            choices = choices.into_iter().map(|a| a.to_lowercase()).collect();
+        }
+        debug_assert!(choices.len() <= CHOICES);
+        question.choices = choices;
+    }
+}
+
+/// Gives the writing questions their strokes, kanji without stroke data can't be asked
+fn add_strokes(queue: &mut Vec<Question>, strokes: &HashMap<String, Vec<String>>) {
+    queue.retain_mut(|question| {
+        if question.question_type != QuestionType::KanjiWriting {
+            return true;
+        }
+        match strokes.get(&question.identifier) {
+            Some(paths) => {
+                question.strokes = paths.clone();
+                true
+            }
+            None => false,
+        }
+    });
+}
 
-    let mut thread = rand::thread_rng();
+/// Every kanji in the files of the levels reviewed
+fn kanji_characters(files: Option<Vec<(usize, File)>>) -> Vec<String> {
+    files
+        .into_iter()
+        .flatten()
+        .flat_map(|(_, file)| file.kanjis.into_iter().map(|a| a.character))
+        .collect()
+}
+
+fn get_question_queue(
+    files: &Vec<(usize, File)>,
+    viewed: &Viewed,
+    vocabs: &Vec<(String, VocabFullInfo)>,
+    should_include: ShouldInclude,
+    synonyms: &Synonyms,
+    order: QuestionOrder,
+    seed: u64,
+) -> Option<Vec<Question>> {
+    let mut queue = Vec::new();
+    let now = srs::now();
+    let mut total_questions = Vec::new();
+    for (level_index, file) in files {
+        let Some(level) = viewed.levels.get(*level_index) else {
+            continue;
+        };
+        total_questions.extend(level_questions(
+            file,
+            *level_index,
+            level,
+            vocabs,
+            &should_include,
+            synonyms,
+            now,
+        ));
+    }
+
+    let mut thread = StdRng::seed_from_u64(seed);
     let mut remaining = total_questions.len();
     while remaining != 0 {
         let index = thread.gen_range(0..remaining);
@@ -258,6 +711,53 @@ fn get_question_queue(
         total_questions.remove(index);
         remaining -= 1;
     }
+    // The sorts are stable so the shuffle still decides ties
+    match order {
+        QuestionOrder::Random => {}
+        QuestionOrder::Paired => {
+            let mut items: Vec<(QuestionType, usize, String)> = Vec::new();
+            for question in &queue {
+                let item = (
+                    question.question_type,
+                    question.level,
+                    question.identifier.clone(),
+                );
+                let same_item = |a: &(QuestionType, usize, String)| {
+                    a.0.mastery_type() == item.0.mastery_type() && a.1 == item.1 && a.2 == item.2
+                };
+                if !items.iter().any(same_item) {
+                    items.push(item);
+                }
+            }
+            queue.sort_by_key(|question| {
+                items.iter().position(|a| {
+                    a.0.mastery_type() == question.question_type.mastery_type()
+                        && a.1 == question.level
+                        && a.2 == question.identifier
+                })
+            });
+        }
+        QuestionOrder::ByType => {
+            queue.sort_by_key(|question| match question.question_type.mastery_type() {
+                MasteryType::Radical => 0,
+                MasteryType::Kanji => 1,
+                MasteryType::Vocabulary => 2,
+            })
+        }
+        QuestionOrder::Mastery => queue.sort_by_key(|question| {
+            viewed
+                .levels
+                .get(question.level)
+                .and_then(|level| {
+                    level
+                        .of_type(question.question_type.mastery_type())
+                        .iter()
+                        .find(|a| a.0 == question.identifier)
+                })
+                .map(|a| a.1.stage)
+                .unwrap_or_default()
+        }),
+    }
     if queue.is_empty() {
         return None;
     }
@@ -273,33 +773,70 @@ pub fn LearningKanji() -> impl IntoView {
     let (_, set_last_reviewed) =
         use_context::<LastReviewedContext>().expect_throw("last reviewed context");
     let (viewed, set_viewed) = use_context::<ViewedContext>().expect_throw("Viewed context");
+    let (synonyms, _) = use_context::<SynonymsContext>().expect_throw("synonyms context");
+    let OverridesContext(_, set_overrides) =
+        use_context::<OverridesContext>().expect_throw("overrides context");
+    let (session, set_session) = use_context::<SessionContext>().expect_throw("session context");
+    let ChoiceWeightContext(choice_weight, _) =
+        use_context::<ChoiceWeightContext>().expect_throw("choice weight context");
+    let (_, set_answers) = use_context::<AnswersContext>().expect_throw("answers context");
+    let leeches = use_context::<LeechesContext>().expect_throw("leeches context");
+    let resume = create_rw_signal(match session.get_untracked() {
+        Some(_) => Resume::Ask,
+        None => Resume::New,
+    });
+    let seed = rand::random::<u64>();
+    let drill = move || {
+        query.with(|query| {
+            query
+                .as_ref()
+                .ok()
+                .and_then(|a| a.leech)
+                .unwrap_or_default()
+        })
+    };
     create_effect(move |_| {
-        if last_reviewed.get() as u32 == today() {
+        // Drills can be done after the reviews
+        if last_reviewed.get() as u32 == today() && !drill() {
             let navigate = use_navigate();
             navigate("/", NavigateOptions::default());
         }
     });
+    let review_all =
+        move || query.with(|query| query.as_ref().ok().and_then(|a| a.all).unwrap_or_default());
+    // Every level with something learned when reviewing everything,
+    // only the selected one otherwise
+    let review_levels = create_memo(move |_| {
+        if !review_all() {
+            return vec![level.get()];
+        }
+        viewed.with(|viewed| {
+            viewed
+                .levels
+                .iter()
+                .enumerate()
+                .filter(|(_, a)| {
+                    !a.kanjis.is_empty() || !a.radicals.is_empty() || !a.vocabs.is_empty()
+                })
+                .map(|(index, _)| index)
+                .collect::<Vec<usize>>()
+        })
+    });
     let res = create_resource(
-        move || level.get(),
-        move |level| async move {
-            #[derive(Serialize)]
-            struct T {
-                level: usize,
-            }
-            let res = invokers::<T, Option<File>>("get_kanjis", T { level: level + 1 })
-                .await
-                .expect_throw("level always should be ok");
-
-            res
-        },
+        move || review_levels.get(),
+        move |levels| async move { get_kanjis_range(levels).await },
     );
     let vocabs = create_resource(
-        move || (level.get(), viewed.get()),
-        |(level, viewed)| async move {
-            let range = viewed
-                .levels
-                .get(level)
-                .map(|view| view.vocabs.iter().map(|a| a.0.clone()).collect())?;
+        move || (review_levels.get(), viewed.get()),
+        |(levels, viewed)| async move {
+            let range: Vec<String> = levels
+                .iter()
+                .flat_map(|level| viewed.levels.get(*level))
+                .flat_map(|view| view.vocabs.iter().map(|a| a.0.clone()))
+                .collect();
+            if range.is_empty() {
+                return None;
+            }
             #[derive(Serialize)]
             struct T {
                 range: Vec<String>,
@@ -312,24 +849,86 @@ pub fn LearningKanji() -> impl IntoView {
         },
     );
 
-    let end_quiz = move |questions| {
-        let new_mastery = with!(move |level, viewed| { add_mastery(questions, viewed, *level) });
-        if let Some(new_mastery) = new_mastery {
-            set_viewed.update(move |a| {
-                a.levels.get_mut(level.get()).map(|a| {
-                    *a = new_mastery;
-                });
-            });
-            let location = use_navigate();
-            set_last_reviewed.set(today() as u8);
-            location("/", NavigateOptions::default());
-        }
+    let choice = move || {
+        query.with(|query| {
+            query
+                .as_ref()
+                .ok()
+                .and_then(|a| a.choice)
+                .unwrap_or_default()
+        })
     };
-    view! {
-        {move || with!(move |query, viewed, res, level, vocabs| {
-            let queue_view = |queue: Vec<Question>| view! {
-                <Quiz queue on_end=end_quiz />
-            }.into_view();
+    let writing = move || {
+        query.with(|query| {
+            query
+                .as_ref()
+                .ok()
+                .and_then(|a| a.writing)
+                .unwrap_or_default()
+        })
+    };
+    // Kanji sharing a radical, the best distractors for multiple choice
+    let neighbours = create_resource(
+        move || (res.get(), choice()),
+        |(res, choice)| async move {
+            let kanjis = kanji_characters(res.flatten());
+            match choice && !kanjis.is_empty() {
+                true => get_radical_neighbours(kanjis).await,
+                false => HashMap::new(),
+            }
+        },
+    );
+    let strokes = create_resource(
+        move || (res.get(), writing()),
+        |(res, writing)| async move {
+            let kanjis = kanji_characters(res.flatten());
+            match writing && !kanjis.is_empty() {
+                true => get_strokes_range(kanjis).await,
+                false => HashMap::new(),
+            }
+        },
+    );
+
+    let end_quiz = move |questions: Vec<QuestionStatus>| {
+        let overridden = questions.iter().filter(|a| a.overridden).count() as u32;
+        set_overrides.update(|a| *a += overridden);
+        let now = srs::now();
+        log_answers(
+            set_answers,
+            questions.iter().map(|a| a.logged(now)).collect(),
+        );
+        let choice_weight = choice_weight.get_untracked();
+        let new_mastery = viewed.with(move |viewed| add_mastery(questions, viewed, choice_weight));
+        set_viewed.set(new_mastery);
+        set_session.set(None);
+        let location = use_navigate();
+        set_last_reviewed.set(today() as u8);
+        location("/", NavigateOptions::default());
+    };
+    // Only logged, so the leeches can stop being ones
+    let end_drill = move |questions: Vec<QuestionStatus>| {
+        let now = srs::now();
+        log_answers(
+            set_answers,
+            questions.iter().map(|a| a.logged(now)).collect(),
+        );
+        set_session.set(None);
+        let location = use_navigate();
+        location("/", NavigateOptions::default());
+    };
+    let new_quiz = move || {
+        with!(move |query, viewed, res, vocabs, neighbours, strokes| {
+            let drill = drill();
+            let queue_view = |queue: Vec<Question>| match drill {
+                true => view! {
+                    <Quiz queue seed drill on_end=end_drill />
+                }
+                .into_view(),
+                false => view! {
+                    <Quiz queue seed on_end=end_quiz />
+                }
+                .into_view(),
+            };
             let empty_view = view! {
                 <div class="empty">
                     its empty
@@ -340,33 +939,142 @@ pub fn LearningKanji() -> impl IntoView {
                 Ok(a) => ShouldInclude {
                     kanji: a.kanji.unwrap_or_default(),
                     radical: a.radical.unwrap_or_default(),
-                    vocab: a.vocab.unwrap_or_default()
+                    vocab: a.vocab.unwrap_or_default(),
+                    reverse: a.reverse.unwrap_or_default(),
+                    writing: a.writing.unwrap_or_default(),
+                    leeches: drill.then(|| leeches.get_untracked()),
                 },
-                _ => ShouldInclude::default()
+                _ => ShouldInclude::default(),
+            };
+            let order = query
+                .as_ref()
+                .ok()
+                .and_then(|a| a.order.as_deref())
+                .and_then(QuestionOrder::from_value)
+                .unwrap_or_default();
+            let choice = query
+                .as_ref()
+                .ok()
+                .and_then(|a| a.choice)
+                .unwrap_or_default();
+            let writing = should_include.writing;
+            if (choice && neighbours.is_none()) || (writing && strokes.is_none()) {
+                return empty_view;
+            }
+            let finish_queue = |mut queue: Vec<Question>, files: &Vec<(usize, File)>| {
+                if let (true, Some(neighbours)) = (choice, neighbours) {
+                    add_choices(&mut queue, files, neighbours, seed);
+                }
+                if let (true, Some(strokes)) = (writing, strokes) {
+                    add_strokes(&mut queue, strokes);
+                }
+                Some(queue).filter(|a| !a.is_empty())
             };
             match (res, vocabs) {
                 (Some(Some(res)), Some(Some(vocabs))) => {
-                    let queue = get_question_queue(res, viewed, *level, vocabs, should_include);
-                    match queue {
+                    let queue = synonyms.with_untracked(|synonyms| {
+                        get_question_queue(
+                            res,
+                            viewed,
+                            vocabs,
+                            should_include,
+                            synonyms,
+                            order,
+                            seed,
+                        )
+                    });
+                    match queue.and_then(|a| finish_queue(a, res)) {
                         Some(a) => queue_view(a),
-                        None => empty_view
+                        None => empty_view,
                     }
                 }
                 (Some(Some(res)), _) => {
-                    let queue = get_question_queue(res, viewed, *level, &Vec::new(), should_include);
-                    match queue {
+                    let queue = synonyms.with_untracked(|synonyms| {
+                        get_question_queue(
+                            res,
+                            viewed,
+                            &Vec::new(),
+                            should_include,
+                            synonyms,
+                            order,
+                            seed,
+                        )
+                    });
+                    match queue.and_then(|a| finish_queue(a, res)) {
                         Some(a) => queue_view(a),
-                        None => empty_view
+                        None => empty_view,
                     }
                 }
-                _ => empty_view
+                _ => empty_view,
             }
-        } )}
+        })
+    };
+    view! {
+        {move || match resume.get() {
+            Resume::Ask => session.get_untracked().map(|saved| {
+                let left = saved.queue.len();
+                let answered = saved.results.len();
+                view! {
+                    <div class="resume">
+                        <span>
+                            "You have a review you didn't finish, "
+                            {answered} " answered and " {left} " left"
+                        </span>
+                        {(left > 0).then(|| view! {
+                            <button on:click=move |_| resume.set(Resume::Resume(saved.clone()))>
+                                Resume
+                            </button>
+                        })}
+                        <button on:click=move |_| match session.get_untracked() {
+                            Some(saved) if saved.drill => end_drill(saved.results),
+                            Some(saved) => end_quiz(saved.results),
+                            None => {}
+                        }>
+                            Save what was answered
+                        </button>
+                        <button on:click=move |_| {
+                            set_session.set(None);
+                            resume.set(Resume::New);
+                        }>
+                            Start over
+                        </button>
+                    </div>
+                }
+            }).into_view(),
+            Resume::Resume(saved) if saved.drill => view! {
+                <Quiz queue=saved.queue results=saved.results seed=saved.seed drill=true on_end=end_drill />
+            }.into_view(),
+            Resume::Resume(saved) => view! {
+                <Quiz queue=saved.queue results=saved.results seed=saved.seed on_end=end_quiz />
+            }.into_view(),
+            Resume::New => new_quiz.into_view(),
+        }}
     }
 }
 
 fn is_correct(question: Question, answer: String) -> AnswerStatus {
+    let synonym = match question.question_type.is_kana() {
+        true => answer.to_hiragana(),
+        false => answer.clone(),
+    };
+    if question.synonyms.contains(&synonym) {
+        return AnswerStatus::Correct;
+    }
     match question.question_type {
+        QuestionType::VocabularyFromMeaning | QuestionType::VocabularyFromReading => {
+            let kana = answer.to_hiragana();
+            for i in &question.answers {
+                // Words written only in kana can be typed in romaji
+                if *i == answer || (i.is_kana() && i.to_hiragana() == kana) {
+                    return AnswerStatus::Correct;
+                }
+            }
+            for i in question.alert_kana {
+                if kana == dislice(&i).to_hiragana() {
+                    return AnswerStatus::SoftError("That's the reading, we want it written");
+                }
+            }
+        }
         QuestionType::KanjiMeaning | QuestionType::VocabularyMeaning | QuestionType::Radical => {
             for i in question.answers {
                 let similarity = fuzzy_compare(&dislice(&i.to_lowercase()), &answer);
@@ -384,24 +1092,37 @@ fn is_correct(question: Question, answer: String) -> AnswerStatus {
             }
         }
         _ => {
-            for i in question.answers {
-                let similarity = fuzzy_compare(&dislice(&i), &answer.to_hiragana());
-                if similarity > 0.8 {
-                    return AnswerStatus::Correct;
-                }
+            let kana = answer.to_hiragana();
+            let expected: Vec<String> = question
+                .answers
+                .iter()
+                .map(|a| dislice(a).to_hiragana())
+                .collect();
+            if expected.contains(&kana) {
+                return AnswerStatus::Correct;
             }
-            let message = if question.question.contains("kunyoumi") {
-                "We're asking the kunyoumi reading"
-            } else {
-                "We're asking the onyoumi reading"
+            let message = match question.question_type {
+                QuestionType::KanjiReadingKun => "We're asking the kunyoumi reading",
+                QuestionType::KanjiReadingOn => "We're asking the onyoumi reading",
+                _ => "We're asking the reading",
             };
             for i in question.alert {
                 if answer.to_romaji() == i.to_lowercase() {
                     return AnswerStatus::SoftError(message);
                 }
             }
+            let swapped = match question.question_type {
+                QuestionType::KanjiReadingKun => "That's the onyoumi, we're asking the kunyoumi",
+                QuestionType::KanjiReadingOn => "That's the kunyoumi, we're asking the onyoumi",
+                _ => message,
+            };
             for i in question.alert_kana {
-                if answer.to_hiragana() == dislice(&i) {
+                if kana == dislice(&i).to_hiragana() {
+                    return AnswerStatus::SoftError(swapped);
+                }
+            }
+            for expected in &expected {
+                if let Some(message) = near_miss(&kana, expected) {
                     return AnswerStatus::SoftError(message);
                 }
             }
@@ -411,7 +1132,17 @@ fn is_correct(question: Question, answer: String) -> AnswerStatus {
 }
 
 #[component]
-fn Quiz<T>(queue: Vec<Question>, on_end: T) -> impl IntoView
+fn Quiz<T>(
+    queue: Vec<Question>,
+    /// Answers from before the session was resumed
+    #[prop(optional)]
+    results: Vec<QuestionStatus>,
+    seed: u64,
+    /// Leech practice, the synonyms can be edited after each answer
+    #[prop(optional)]
+    drill: bool,
+    on_end: T,
+) -> impl IntoView
 where
     T: Fn(Vec<QuestionStatus>) -> () + Copy + 'static,
 {
@@ -420,7 +1151,41 @@ where
     let answer = create_rw_signal(String::new());
     let answer_status = create_rw_signal(None::<AnswerStatus>);
     let current_question = move || queue.with(|queue| queue.get(index.get()).cloned());
-    let question_status = create_rw_signal(Vec::<QuestionStatus>::new());
+    let question_status = create_rw_signal(results);
+    let (_, set_session) = use_context::<SessionContext>().expect_throw("session context");
+    create_effect(move |_| {
+        let session = with!(|queue, index, question_status| Session {
+            queue: queue[*index..].to_vec(),
+            results: question_status.clone(),
+            seed,
+            drill,
+        });
+        set_session.set(Some(session));
+    });
+    let overridden = create_rw_signal(false);
+    // The answer on screen if it was wrong, otherwise the last one sent
+    // when nothing was answered yet
+    let can_override = move || match answer_status.get() {
+        Some(status) => status.is_wrong(),
+        None => question_status.with(|a| a.last().is_some_and(|a| !a.is_correct)),
+    };
+    let count_as_correct = move || {
+        if !can_override() {
+            return;
+        }
+        match answer_status.get_untracked() {
+            Some(_) => {
+                answer_status.set(Some(AnswerStatus::Correct));
+                overridden.set(true);
+            }
+            None => question_status.update(|a| {
+                if let Some(last) = a.last_mut() {
+                    last.is_correct = true;
+                    last.overridden = true;
+                }
+            }),
+        }
+    };
     let go_to_next = move || {
         let has_next = with!(move |queue, index| queue.get(*index + 1).is_some());
         let new_question_status = with!(move |index, queue, answer_status| {
@@ -428,12 +1193,18 @@ where
             Some(QuestionStatus {
                 identifier: current_question.identifier.clone(),
                 is_correct: answer_status.map(|a| a.is_correct()).unwrap_or_default(),
+                overridden: overridden.get(),
+                multiple_choice: !current_question.choices.is_empty(),
                 question_type: current_question.question_type,
+                level: current_question.level,
+                answered_at: srs::now(),
+                bit_off: *answer_status == Some(AnswerStatus::CorrectBitOff),
             })
         });
         if let Some(new_question_status) = &new_question_status {
             question_status.update(move |a| a.push(new_question_status.clone()));
         }
+        overridden.set(false);
         if has_next {
             answer_status.update(|a| *a = None);
             answer.update(|a| *a = String::new());
@@ -472,13 +1243,76 @@ where
             answer_status.set(Some(is_correct(question, answer)));
         }
     };
+    let choose = move |choice: String| {
+        if answer_status.get_untracked().is_some() {
+            return;
+        }
+        let Some(question) = current_question() else {
+            return;
+        };
+        let status = match is_answer(&question.answers, &choice) {
+            true => AnswerStatus::Correct,
+            false => AnswerStatus::HardError,
+        };
+        answer.set(choice);
+        answer_status.set(Some(status));
+    };
+    // A few missed strokes are still right, a quarter of them is too many
+    let finish_writing = move |misses: usize| {
+        let count = current_question()
+            .map(|a| a.strokes.len())
+            .unwrap_or_default();
+        let status = match misses {
+            0 => AnswerStatus::Correct,
+            _ if misses * 4 < count => AnswerStatus::CorrectBitOff,
+            _ => AnswerStatus::HardError,
+        };
+        answer_status.set(Some(status));
+    };
+    // The number keys pick a choice and enter goes on since there is no form to submit
+    let handle = window_event_listener(ev::keydown, move |ev| {
+        // Only while a wrong answer is on screen, otherwise it is the
+        // undo of whatever is being typed in the next answer
+        let wrong_on_screen = answer_status.get_untracked().is_some_and(|a| a.is_wrong());
+        if ev.ctrl_key() && ev.key() == "z" && wrong_on_screen {
+            ev.prevent_default();
+            count_as_correct();
+            return;
+        }
+        let Some(question) = current_question() else {
+            return;
+        };
+        // Typed answers have the form for this
+        if question.choices.is_empty() && question.question_type != QuestionType::KanjiWriting {
+            return;
+        }
+        let choices = question.choices;
+        match ev.key().as_str() {
+            // Also keeps enter from clicking the focused choice on the next question
+            "Enter" if answer_status.get_untracked().is_some() => {
+                ev.prevent_default();
+                go_to_next();
+            }
+            key => {
+                let picked = key
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|a| a.checked_sub(1))
+                    .and_then(|a| choices.get(a));
+                if let Some(picked) = picked {
+                    choose(picked.clone());
+                }
+            }
+        }
+    });
+    on_cleanup(move || handle.remove());
     let next_5 = move || {
         queue.with(|queue| {
             (index.get() + 1..index.get() + 6)
                 .flat_map(|index| {
                     queue.get(index).map(|a| {
                         (
-                            a.identifier.clone(),
+                            a.prompt.clone().unwrap_or(a.identifier.clone()),
                             a.question_type,
                             a.radical_character.clone(),
                         )
@@ -519,33 +1353,69 @@ where
                     class:kanji=a.question_type.is_kanji()
                     class:radical=a.question_type.is_radical()
                 >
-                    {match a.radical_character {
-                        Some(character) => view! {
+                    {match (a.radical_character, a.prompt) {
+                        (Some(character), _) => view! {
                             <ShowRadical radical=Radical { character, meaning: a.identifier } />
                         }.into_view(),
-                        None => a.identifier.into_view()
+                        (None, Some(prompt)) => prompt.into_view(),
+                        (None, None) => a.identifier.into_view()
                     }}
                 </div>
                 <span class="question">
                     {a.question.clone()}
                 </span>
-                <div>
-                    <input
-                        ref=reff
-                        type="text"
-                        prop:value=move || answer.get()
-                        on:keyup=move |ev| answer.set(event_target_value(&ev))
-                        on:change=move |ev| answer.set(event_target_value(&ev))
-                    />
+                {match (a.question_type == QuestionType::KanjiWriting, !a.choices.is_empty()) {
+                    (true, _) => view! {
+                        <WritingPad kanji=a.identifier.clone() paths=a.strokes.clone() on_done=finish_writing />
+                    }.into_view(),
+                    (false, true) => view! {
+                        <div class="choices">
+                            {a.choices.iter().enumerate().map(|(number, choice)| {
+                                let picked = choice.clone();
+                                let choice = choice.clone();
+                                view! {
+                                    <button
+                                        type="button"
+                                        class:picked=move || answer.with(|a| *a == picked)
+                                        on:click=move |_| choose(choice.clone())
+                                    >
+                                        <span class="key">
+                                            {number + 1}
+                                        </span>
+                                        {choice.clone()}
+                                    </button>
+                                }
+                            }).collect_view()}
+                        </div>
+                    }.into_view(),
+                    (false, false) => view! {
+                        <div>
+                            <input
+                                ref=reff
+                                type="text"
+                                prop:value=move || answer.get()
+                                on:keyup=move |ev| answer.set(event_target_value(&ev))
+                                on:change=move |ev| answer.set(event_target_value(&ev))
+                            />
 
-                    <button>
-                        confirm
-                    </button>
-                </div>
+                            <button>
+                                confirm
+                            </button>
+                        </div>
+                    }.into_view(),
+                }}
                 <span class="translate">
-                    {move || (a.question_type.is_kana() && !answer.get().is_hiragana()).then(|| view! {
-                        {answer.get().to_hiragana()}
-                    })}
+                    {move || {
+                        let answer = answer.get();
+                        let untranslated = match a.question_type.is_written() {
+                            _ if !a.choices.is_empty() => false,
+                            true => !answer.is_japanese(),
+                            false => a.question_type.is_kana() && !answer.is_hiragana(),
+                        };
+                        untranslated.then(|| view! {
+                            {answer.to_hiragana()}
+                        })
+                    }}
                 </span>
             </form>
             <button class="next" on:click=move |_| if answer_status.get().is_some() {
@@ -553,6 +1423,18 @@ where
             }>
                 next
             </button>
+            {move || can_override().then(|| view! {
+                <button
+                    class="override"
+                    title=move || answer_status.get().is_some().then_some("Ctrl+Z")
+                    on:click=move |_| count_as_correct()
+                >
+                    {match answer_status.get().is_some() {
+                        true => "I typed it wrong",
+                        false => "The last one was a typo",
+                    }}
+                </button>
+            })}
             {move || answer_status.get().map(|status| {
                 let valid_answer = view! {
                     <h4>
@@ -563,6 +1445,11 @@ where
                             - {dislice(answer)}
                         </span>
                     }).collect_view()}
+                    {a.synonyms.iter().map(|answer| view! {
+                        <span class="synonym">
+                            - {answer.clone()}
+                        </span>
+                    }).collect_view()}
                 };
                 match status {
                     AnswerStatus::SoftError(err) => view! {
@@ -592,6 +1479,13 @@ where
                     }.into_view()
                 }
             })}
+            {move || answer_status.get().and(current_question()).filter(|_| drill).map(|question| view! {
+                <SynonymsEditor
+                    kind=question.question_type.mastery_type()
+                    identifier=question.identifier
+                    readings=!question.question_type.is_radical()
+                />
+            })}
         },
         None => view! {
             <>
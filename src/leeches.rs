@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use leptos::*;
+use wasm_bindgen::UnwrapThrowExt;
+
+use crate::{home::MasteryType, stats::Answer};
+
+/// Only the latest answers of an item count, so a leech stops being one
+/// once it is learned
+const LEECH_WINDOW: usize = 20;
+/// Misses in the window that make an item a leech
+const LEECH_MISSES: usize = 8;
+/// Or the share of misses, in percent, once there are enough answers
+const LEECH_RATIO: usize = 40;
+const LEECH_MIN_ANSWERS: usize = 6;
+
+/// An item that keeps getting missed
+#[derive(Clone, PartialEq, Eq)]
+pub struct Leech {
+    pub kind: MasteryType,
+    /// Counting from 0
+    pub level: usize,
+    pub identifier: String,
+    pub misses: usize,
+    pub answers: usize,
+}
+impl Leech {
+    pub fn describe(&self) -> String {
+        format!(
+            "Leech: missed {} of the last {} answers",
+            self.misses, self.answers
+        )
+    }
+}
+pub type LeechesContext = Memo<Vec<Leech>>;
+
+/// Every leech in the answer log, by level
+pub fn find_leeches(answers: &[Answer]) -> Vec<Leech> {
+    let mut items: HashMap<(MasteryType, usize, &str), Vec<&Answer>> = HashMap::new();
+    for answer in answers {
+        let key = (
+            answer.question_type.mastery_type(),
+            answer.level,
+            answer.identifier.as_str(),
+        );
+        items.entry(key).or_default().push(answer);
+    }
+    let mut leeches: Vec<Leech> = items
+        .into_iter()
+        .filter_map(|((kind, level, identifier), mut answers)| {
+            answers.sort_by_key(|a| a.time);
+            let recent = &answers[answers.len().saturating_sub(LEECH_WINDOW)..];
+            // An overridden answer was still wrong at first
+            let misses = recent.iter().filter(|a| !a.is_right()).count();
+            let too_many = misses >= LEECH_MISSES;
+            let too_often =
+                recent.len() >= LEECH_MIN_ANSWERS && misses * 100 >= LEECH_RATIO * recent.len();
+            (too_many || too_often).then(|| Leech {
+                kind,
+                level,
+                identifier: identifier.to_string(),
+                misses,
+                answers: recent.len(),
+            })
+        })
+        .collect();
+    leeches.sort_by(|a, b| (a.level, &a.identifier).cmp(&(b.level, &b.identifier)));
+    leeches
+}
+
+pub fn find_leech<'a>(
+    leeches: &'a [Leech],
+    kind: MasteryType,
+    level: usize,
+    identifier: &str,
+) -> Option<&'a Leech> {
+    leeches
+        .iter()
+        .find(|a| a.kind == kind && a.level == level && a.identifier == identifier)
+}
+
+/// Badge for the items in the home grids, nothing if it is not a leech
+#[component]
+pub fn LeechBadge(kind: MasteryType, level: usize, identifier: String) -> impl IntoView {
+    let leeches = use_context::<LeechesContext>().expect_throw("leeches context");
+    move || {
+        leeches.with(|leeches| {
+            find_leech(leeches, kind, level, &identifier).map(|leech| {
+                view! {
+                    <span class="leech" title=leech.describe()>
+                        leech
+                    </span>
+                }
+            })
+        })
+    }
+}
@@ -1,14 +1,28 @@
+mod anki_export;
 mod app;
 mod ask;
 mod button_link;
+mod choices;
 mod home;
 mod invoke;
 mod kanji_info;
 mod learningkanji;
+mod leeches;
+mod packs;
+mod progress;
+mod radical_image;
 mod radical_info;
-mod svgs;
+mod readings;
+mod search;
+#[path = "../src-tauri/src/settings.rs"]
+mod settings;
+mod srs;
+mod stats;
+mod strokes;
+mod synonyms;
 mod utils;
 mod vocab_info;
+mod writing;
 use app::*;
 use leptos::*;
 use leptos_router::Router;
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+use crate::invoke::invokers;
+
+/// Levels that come with the app, packs start after them
+pub const BUNDLED_LEVELS: usize = 60;
+
+/// Extra levels loaded from the packs directory
+#[derive(Clone, PartialEq, Eq, Default, Deserialize, Serialize, Debug)]
+pub struct PackInfo {
+    pub name: String,
+    pub version: String,
+    /// Counting from 1 like the backend does
+    pub first_level: usize,
+    pub level_count: usize,
+}
+
+#[derive(Clone, PartialEq, Eq, Deserialize, Serialize, Debug)]
+pub struct LevelsInfo {
+    pub count: usize,
+    pub packs: Vec<PackInfo>,
+}
+impl Default for LevelsInfo {
+    fn default() -> Self {
+        Self {
+            count: BUNDLED_LEVELS,
+            packs: Vec::new(),
+        }
+    }
+}
+impl LevelsInfo {
+    pub fn pack_of(&self, level: usize) -> Option<&PackInfo> {
+        self.packs
+            .iter()
+            .find(|a| (a.first_level - 1..a.first_level - 1 + a.level_count).contains(&level))
+    }
+    /// Levels that don't need the one before them, counting from 0
+    pub fn starts_pack(&self, level: usize) -> bool {
+        level == 0 || self.packs.iter().any(|a| a.first_level - 1 == level)
+    }
+    pub fn level_name(&self, level: usize) -> String {
+        match self.pack_of(level) {
+            Some(pack) => format!("{} {}", pack.name, level + 2 - pack.first_level),
+            None => format!("{}", level + 1),
+        }
+    }
+}
+
+pub async fn get_levels_info() -> LevelsInfo {
+    #[derive(Serialize)]
+    struct T {}
+    invokers("get_levels_info", T {}).await.unwrap_or_default()
+}
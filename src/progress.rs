@@ -0,0 +1,156 @@
+use leptos::*;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::UnwrapThrowExt;
+
+use crate::{
+    invoke::{invoke_result, invokers},
+    learningkanji::Session,
+    settings::DEFAULT_CHOICE_WEIGHT,
+    stats::{load_answers, AnswersContext},
+    synonyms::Synonyms,
+    utils::{alert_errors, local_get},
+    Viewed,
+};
+
+/// Replaces every piece of progress the app is holding
+pub type ApplyProgressContext = Callback<Progress>;
+
+/// What gets saved in the progress file owned by the backend
+#[derive(Clone, PartialEq, Eq, Default, Deserialize, Serialize, Debug)]
+pub struct Progress {
+    pub viewed: Viewed,
+    pub level: usize,
+    pub times_learned: u32,
+    pub last_reviewed: u8,
+    pub last_learned: u32,
+    #[serde(default)]
+    pub synonyms: Synonyms,
+    #[serde(default)]
+    pub overrides: u32,
+    #[serde(default = "default_choice_weight")]
+    pub choice_weight: u32,
+    #[serde(default)]
+    pub session: Option<Session>,
+}
+
+fn default_choice_weight() -> u32 {
+    DEFAULT_CHOICE_WEIGHT
+}
+
+impl Progress {
+    /// Progress saved by the older versions that only used localStorage
+    pub fn from_local_storage() -> Self {
+        let mut buff = String::new();
+        Self {
+            viewed: local_get("viewed".to_string(), &mut buff).unwrap_or_default(),
+            level: local_get("level".to_string(), &mut buff).unwrap_or_default(),
+            times_learned: local_get("times_learned".to_string(), &mut buff).unwrap_or_default(),
+            last_reviewed: local_get("last_reviewed".to_string(), &mut buff).unwrap_or_default(),
+            last_learned: local_get("last_learned".to_string(), &mut buff).unwrap_or_default(),
+            synonyms: Synonyms::default(),
+            overrides: 0,
+            choice_weight: DEFAULT_CHOICE_WEIGHT,
+            session: None,
+        }
+    }
+}
+
+/// None when nothing was saved yet, an error when the file is there but can't be read
+pub async fn load_progress() -> Result<Option<Progress>, String> {
+    #[derive(Serialize)]
+    struct T {}
+    invoke_result("load_progress", T {}).await
+}
+
+pub async fn save_progress(progress: Progress) -> bool {
+    #[derive(Serialize)]
+    struct T {
+        progress: Progress,
+    }
+    invokers("save_progress", T { progress })
+        .await
+        .unwrap_or_default()
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ImportMode {
+    Merge,
+    Replace,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+pub struct ImportReport {
+    pub progress: Option<Progress>,
+    pub errors: Vec<String>,
+}
+
+/// False if cancelled
+pub async fn export_progress() -> Result<bool, String> {
+    #[derive(Serialize)]
+    struct T {}
+    invoke_result("export_progress", T {}).await
+}
+
+pub async fn import_progress(mode: ImportMode) -> ImportReport {
+    #[derive(Serialize)]
+    struct T {
+        mode: ImportMode,
+    }
+    invokers("import_progress", T { mode })
+        .await
+        .unwrap_or_default()
+}
+
+#[component]
+pub fn ProgressTransfer() -> impl IntoView {
+    let apply_progress =
+        use_context::<ApplyProgressContext>().expect_throw("apply progress context");
+    let (_, set_answers) = use_context::<AnswersContext>().expect_throw("answers context");
+    let import = move |mode: ImportMode| {
+        if mode == ImportMode::Replace {
+            let confirmed = window()
+                .confirm_with_message(
+                    "Are you sure you want to replace your progress?\nThere will be no turning back",
+                )
+                .unwrap_or_default();
+            if !confirmed {
+                return;
+            }
+        }
+        spawn_local(async move {
+            let report = import_progress(mode).await;
+            if let Some(progress) = report.progress {
+                apply_progress.call(progress);
+                // The imported answers went straight to the log
+                set_answers.set(load_answers().await);
+                if !report.errors.is_empty() {
+                    alert_errors("The progress was imported, but", &report.errors);
+                }
+            } else if !report.errors.is_empty() {
+                alert_errors("Couldn't import this file", &report.errors);
+            }
+        });
+    };
+    view! {
+        <section class="transfer">
+            <h3>
+                Your progress
+            </h3>
+            <div>
+                <button on:click=move |_| spawn_local(async move {
+                    if let Err(err) = export_progress().await {
+                        alert_errors("Couldn't export your progress", &[err]);
+                    }
+                })>
+                    Export
+                </button>
+                <button on:click=move |_| import(ImportMode::Merge)>
+                    Import and merge
+                </button>
+                <button on:click=move |_| import(ImportMode::Replace)>
+                    Import and replace
+                </button>
+            </div>
+        </section>
+    }
+}
@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use leptos::*;
+use serde::Serialize;
+
+use crate::invoke::invokers;
+
+/// Artwork of the radicals without a character, keyed by `radical_id`
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct RadicalImages(pub HashMap<String, String>);
+
+pub type RadicalImagesContext = Resource<(), RadicalImages>;
+
+pub async fn get_radical_images() -> RadicalImages {
+    #[derive(Serialize)]
+    struct T {}
+    RadicalImages(
+        invokers("get_radical_images", T {})
+            .await
+            .unwrap_or_default(),
+    )
+}
+
+/// Same as the backend, `Death Star` is `death-star`
+pub fn radical_id(meaning: &str) -> String {
+    meaning.trim().to_lowercase().replace(' ', "-")
+}
+
+/// Artwork of a radical, the first letter of its meaning when there is none
+#[component]
+pub fn RadicalImage(meaning: String) -> impl IntoView {
+    let images = use_context::<RadicalImagesContext>().expect_throw("radical images context");
+    let id = radical_id(&meaning);
+    move || {
+        images.with(|images| {
+            let images = images.as_ref()?;
+            Some(match images.0.get(&id) {
+                Some(svg) => view! {
+                    <span class="radical_image" inner_html=svg.clone() />
+                }
+                .into_view(),
+                None => view! {
+                    <span class="radical_image missing" title=meaning.clone()>
+                        {meaning.chars().next().map(|a| a.to_uppercase().to_string())}
+                    </span>
+                }
+                .into_view(),
+            })
+        })
+    }
+}
@@ -2,6 +2,7 @@ use chrono::Datelike;
 use leptos::*;
 use leptos_router::use_params;
 use leptos_router::Params;
+use leptos_router::A;
 use serde::Deserialize;
 use serde::Serialize;
 use wasm_bindgen::UnwrapThrowExt;
@@ -10,10 +11,14 @@ use web_sys::MouseEvent;
 use crate::button_link::ButtonLink;
 use crate::has_acess_logic;
 use crate::home::File;
+use crate::home::MasteryType;
 use crate::home::Radical;
 use crate::home::ShowRadical;
 use crate::invoke::invokers;
-use crate::utils::local_set;
+use crate::packs::LevelsInfo;
+use crate::srs::Srs;
+use crate::synonyms::SynonymsEditor;
+use crate::LastLearnedContext;
 use crate::ViewedContext;
 use crate::LIMIT;
 
@@ -23,6 +28,14 @@ pub struct RadicalFullInfo {
     wk_level: Option<u32>,
 }
 
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KanjiSummary {
+    character: String,
+    meaning: String,
+    /// Counting from 0
+    level: Option<usize>,
+}
+
 #[derive(Clone, PartialEq, Eq, Params)]
 pub struct RadicalParams {
     radical_meaning: Option<String>,
@@ -34,6 +47,9 @@ pub fn RadicalInfo() -> impl IntoView {
     let params = use_params::<RadicalParams>();
     let (times_learned, set_times_learned) =
         use_context::<(Signal<u32>, WriteSignal<u32>)>().expect_throw("times learned context");
+    let LastLearnedContext(_, set_last_learned) =
+        use_context::<LastLearnedContext>().expect_throw("last learned context");
+    let levels_info = use_context::<Signal<LevelsInfo>>().expect_throw("levels info context");
     let meaning = move || match params.get() {
         Ok(a) => a.radical_meaning,
         _ => None,
@@ -58,6 +74,31 @@ pub fn RadicalInfo() -> impl IntoView {
         },
     );
 
+    let used_in = create_resource(
+        move || meaning(),
+        move |a| async move {
+            let Some(meaning) = a else {
+                return Vec::new();
+            };
+            #[derive(Serialize)]
+            struct T {
+                meaning: String,
+            }
+            invokers::<T, Vec<KanjiSummary>>("get_radical_kanjis", T { meaning })
+                .await
+                .unwrap_or_default()
+        },
+    );
+    let is_learned = move |kanji: &KanjiSummary| {
+        viewed.with(|viewed| {
+            kanji
+                .level
+                .and_then(|a| viewed.levels.get(a))
+                .map(|a| a.kanjis.iter().any(|a| a.0 == kanji.character))
+                .unwrap_or_default()
+        })
+    };
+
     let has_learned = create_memo(move |_| {
         with!(move |viewed, info| {
             let level = match info {
@@ -76,13 +117,13 @@ pub fn RadicalInfo() -> impl IntoView {
     });
 
     let has_acess = create_resource(
-        move || (viewed.get(), info.get()),
-        move |(viewed, info)| async move {
+        move || (viewed.get(), info.get(), levels_info.get()),
+        move |(viewed, info, levels_info)| async move {
             let level = match info {
                 Some(Some(a)) if a.wk_level.is_some() => a.wk_level.unwrap() - 1,
                 _ => return false,
             };
-            if level <= 0 {
+            if levels_info.starts_pack(level as usize) {
                 return true;
             }
             #[derive(Serialize)]
@@ -133,11 +174,11 @@ pub fn RadicalInfo() -> impl IntoView {
                 return;
             }
             let now = chrono::offset::Local::now();
-            local_set("last_learned".to_string(), now.date_naive().day());
+            set_last_learned.set(now.date_naive().day());
             set_times_learned.update(|a| {
                 *a = *a + 1;
             });
-            level_viewed.radicals.push((meaning, 0));
+            level_viewed.radicals.push((meaning, Srs::default()));
         })
     };
     let info = move || info.get().flatten();
@@ -168,6 +209,31 @@ pub fn RadicalInfo() -> impl IntoView {
                     </div>
                 </div>
             </div>
+            {move || used_in.get().filter(|a| !a.is_empty()).map(|kanjis| view! {
+                <section class="used_in">
+                    <h3>
+                        Used in
+                    </h3>
+                    <div>
+                        {kanjis.into_iter().map(|kanji| {
+                            let learned = is_learned(&kanji);
+                            view! {
+                            <A class=move || learned.then(|| "viewed").unwrap_or_default() href=format!("/kanji/{}", kanji.character)>
+                                <span class="character"> {kanji.character.clone()} </span>
+                                <span> {kanji.meaning.clone()} </span>
+                                {kanji.level.map(|a| view! {
+                                    <span class="level"> Level {levels_info.with(|info| info.level_name(a))} </span>
+                                })}
+                            </A>
+                            }
+                        }).collect_view()}
+                    </div>
+                </section>
+            })}
+            <SynonymsEditor
+                kind=MasteryType::Radical
+                identifier=Signal::derive(move || meaning().unwrap_or_default())
+            />
             {move || meaning().map(|meaning| view! {
                 <section class="other_sources">
                     <h3>
@@ -0,0 +1,122 @@
+//! Tells apart the usual ways of almost getting a reading right.
+//! Both sides are expected in hiragana
+
+const VOICED: &str = "がぎぐげござじずぜぞだぢづでどばびぶべぼぱぴぷぺぽゔ";
+const UNVOICED: &str = "かきくけこさしすせそたちつてとはひふへほはひふへほう";
+
+const VOWELS: [(char, &str); 5] = [
+    ('あ', "あかがさざただなはばぱまやらわぁゃゎ"),
+    ('い', "いきぎしじちぢにひびぴみりぃ"),
+    ('う', "うくぐすずつづぬふぶぷむゆるゔぅゅ"),
+    ('え', "えけげせぜてでねへべぺめれぇ"),
+    ('お', "おこごそぞとどのほぼぽもよろをぉょ"),
+];
+
+fn unvoiced(value: &str) -> String {
+    value
+        .chars()
+        .map(|a| match VOICED.chars().position(|b| b == a) {
+            Some(index) => UNVOICED.chars().nth(index).unwrap_or(a),
+            None => a,
+        })
+        .collect()
+}
+
+fn vowel_of(kana: char) -> Option<char> {
+    VOWELS
+        .iter()
+        .find(|(_, row)| row.contains(kana))
+        .map(|(vowel, _)| *vowel)
+}
+
+/// Drops the kana that only make the previous vowel longer, so おおきい becomes おき
+fn short_vowels(value: &str) -> String {
+    let mut result = String::new();
+    let mut previous = None;
+    for kana in value.chars() {
+        let lengthens = match (previous, kana) {
+            (Some(_), 'ー') => true,
+            (Some('お'), 'う') | (Some('え'), 'い') => true,
+            (Some(vowel), kana) => vowel == kana,
+            (None, _) => false,
+        };
+        if lengthens {
+            continue;
+        }
+        previous = vowel_of(kana);
+        result.push(kana);
+    }
+    result
+}
+
+fn without_small_tsu(value: &str) -> String {
+    value.chars().filter(|a| *a != 'っ').collect()
+}
+
+/// Why `answer` is not quite `expected`, None if it isn't close at all
+pub fn near_miss(answer: &str, expected: &str) -> Option<&'static str> {
+    if answer == expected {
+        return None;
+    }
+    if unvoiced(answer) == unvoiced(expected) {
+        return Some("Close, check the dakuten. Compounds often voice the next sound (rendaku)");
+    }
+    if short_vowels(answer) == short_vowels(expected) {
+        return Some("Close, check the length of the vowels");
+    }
+    if without_small_tsu(answer) == without_small_tsu(expected) {
+        return Some("Close, check the small っ");
+    }
+    let loose = |value: &str| without_small_tsu(&short_vowels(&unvoiced(value)));
+    if loose(answer) == loose(expected) {
+        return Some("Close, check the dakuten, small っ and long vowels");
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_match_is_not_a_miss() {
+        assert_eq!(near_miss("にほん", "にほん"), None);
+    }
+
+    #[test]
+    fn unrelated_reading_is_not_a_miss() {
+        assert_eq!(near_miss("やま", "かわ"), None);
+    }
+
+    #[test]
+    fn dakuten() {
+        let miss = near_miss("ひとひと", "ひとびと").unwrap();
+        assert!(miss.contains("dakuten"));
+        let miss = near_miss("かいしや", "がいしや").unwrap();
+        assert!(miss.contains("dakuten"));
+    }
+
+    #[test]
+    fn long_vowels() {
+        let miss = near_miss("おばさん", "おばあさん").unwrap();
+        assert!(miss.contains("length"));
+        let miss = near_miss("とうきょ", "とうきょう").unwrap();
+        assert!(miss.contains("length"));
+        let miss = near_miss("せんせ", "せんせい").unwrap();
+        assert!(miss.contains("length"));
+    }
+
+    #[test]
+    fn small_tsu() {
+        let miss = near_miss("きて", "きって").unwrap();
+        assert!(miss.contains("small っ"));
+        let miss = near_miss("がっこう", "がこう").unwrap();
+        assert!(miss.contains("small っ"));
+    }
+
+    #[test]
+    fn several_at_once() {
+        let miss = near_miss("かこ", "がっこう").unwrap();
+        assert!(miss.contains("dakuten, small っ and long vowels"));
+    }
+}
@@ -0,0 +1,184 @@
+use leptos::*;
+use leptos_router::{use_navigate, use_query, NavigateOptions, Params, A};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::UnwrapThrowExt;
+
+use crate::{
+    home::{Radical, ShowRadical},
+    invoke::invokers,
+    strokes::has_strokes,
+    writing::{Point, SketchPad},
+};
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SearchKind {
+    Kanji,
+    Vocabulary,
+    Radical,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct SearchResult {
+    pub kind: SearchKind,
+    pub identifier: String,
+    pub character: String,
+    pub meaning: String,
+    pub reading: String,
+    pub wk_level: Option<u32>,
+    pub score: f32,
+}
+
+#[derive(Params, PartialEq, Eq, Default)]
+struct SearchQuery {
+    q: Option<String>,
+}
+
+/// Kanji that look like the drawing, best first
+async fn search_drawing(strokes: Vec<Vec<Point>>) -> Vec<SearchResult> {
+    #[derive(Serialize)]
+    struct T {
+        strokes: Vec<Vec<Point>>,
+    }
+    invokers("search_drawing", T { strokes })
+        .await
+        .unwrap_or_default()
+}
+
+fn result_link(result: SearchResult) -> impl IntoView {
+    let href = match result.kind {
+        SearchKind::Kanji => format!("/kanji/{}", result.identifier),
+        SearchKind::Vocabulary => format!("/vocab/{}", result.identifier),
+        SearchKind::Radical => format!("/radical/{}", result.identifier),
+    };
+    let character = match result.kind {
+        SearchKind::Radical => view! {
+            <ShowRadical radical=Radical {
+                character: result.character.clone(),
+                meaning: result.identifier.clone()
+            } />
+        }
+        .into_view(),
+        _ => result.character.clone().into_view(),
+    };
+    view! {
+        <A
+            href
+            class=move || match result.kind {
+                SearchKind::Kanji => "result kanji",
+                SearchKind::Vocabulary => "result vocab",
+                SearchKind::Radical => "result radical",
+            }
+        >
+            <span class="character">
+                {character}
+            </span>
+            <span>
+                {result.meaning.clone()}
+            </span>
+            <span>
+                {result.reading.clone()}
+            </span>
+            {result.wk_level.map(|a| view! {
+                <span class="level">
+                    Level {a}
+                </span>
+            })}
+        </A>
+    }
+}
+
+#[component]
+pub fn Search() -> impl IntoView {
+    let query = use_query::<SearchQuery>();
+    let text = move || {
+        query.with(|query| {
+            query
+                .as_ref()
+                .ok()
+                .and_then(|a| a.q.clone())
+                .unwrap_or_default()
+        })
+    };
+    let results = create_resource(text, |query| async move {
+        #[derive(Serialize)]
+        struct T {
+            query: String,
+        }
+        invokers::<T, Vec<SearchResult>>("search", T { query })
+            .await
+            .expect_throw("failed to search")
+    });
+    // Drawing needs the stroke data to compare with
+    let strokes_loaded = create_resource(|| (), |_| has_strokes());
+    // Strokes drawn when looking up a kanji that can't be typed
+    let drawing = create_rw_signal(None::<Vec<Vec<Point>>>);
+    // Only changes when opening or closing, so the pad keeps its strokes
+    let is_drawing = create_memo(move |_| drawing.with(|a| a.is_some()));
+    let drawn_results = create_resource(
+        move || drawing.get().unwrap_or_default(),
+        |strokes| async move {
+            match strokes.is_empty() {
+                true => Vec::new(),
+                false => search_drawing(strokes).await,
+            }
+        },
+    );
+    // Keeps the search in the url so going back shows the same results
+    let navigate = use_navigate();
+    let on_input = move |ev: ev::Event| {
+        navigate(
+            &format!(
+                "/search?q={}",
+                js_sys::encode_uri_component(&event_target_value(&ev))
+            ),
+            NavigateOptions {
+                replace: true,
+                ..Default::default()
+            },
+        );
+    };
+    view! {
+        <section class="search">
+            <div class="bar">
+                <input
+                    type="search"
+                    placeholder="Kanji, reading or meaning"
+                    prop:value=text
+                    on:input=on_input
+                />
+                {move || strokes_loaded.get().unwrap_or_default().then(|| view! {
+                    <button
+                        class:active=is_drawing
+                        on:click=move |_| drawing.update(|a| {
+                            *a = match a {
+                                Some(_) => None,
+                                None => Some(Vec::new()),
+                            }
+                        })
+                    >
+                        Draw
+                    </button>
+                })}
+            </div>
+            {move || is_drawing.get().then(|| view! {
+                <SketchPad on_change=move |strokes| drawing.set(Some(strokes)) />
+                {move || drawn_results.get().map(|results| {
+                    results.into_iter().map(result_link).collect_view()
+                })}
+            })}
+            {move || results.get().map(|results| {
+                if is_drawing.get() || text().trim().is_empty() {
+                    return ().into_view();
+                }
+                if results.is_empty() {
+                    return view! {
+                        <div class="empty">
+                            Nothing found
+                        </div>
+                    }.into_view();
+                }
+                results.into_iter().map(result_link).collect_view()
+            })}
+        </section>
+    }
+}
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+
+/// Ease is kept in permille so the state can still derive `Eq`
+pub const STARTING_EASE: u32 = 2500;
+pub const MINIMUM_EASE: u32 = 1300;
+const EASE_STEP: u32 = 200;
+/// Stages lost for every wrong answer in a session
+pub const PENALTY_STAGES: usize = 2;
+const DAY: i64 = 60 * 60 * 24;
+/// Items that become due in the next few hours already count as due,
+/// so something reviewed at night shows up again the next morning
+const LEEWAY: i64 = 60 * 60 * 6;
+
+pub fn now() -> i64 {
+    chrono::Utc::now().timestamp()
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(from = "SrsRepr")]
+pub struct Srs {
+    /// Mastery of the item, what the progress bars show
+    pub stage: usize,
+    /// Days until the next review
+    pub interval: u32,
+    pub ease: u32,
+    /// Unix timestamp in seconds
+    pub due: i64,
+    /// Part of a stage earned by multiple choice answers that doesn't make a
+    /// whole one yet, in percent
+    pub credit: u32,
+}
+
+/// Old saves only had the mastery counter for each item
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SrsRepr {
+    Legacy(usize),
+    Full {
+        stage: usize,
+        interval: u32,
+        ease: u32,
+        due: i64,
+        #[serde(default)]
+        credit: u32,
+    },
+}
+
+impl From<SrsRepr> for Srs {
+    fn from(value: SrsRepr) -> Self {
+        match value {
+            SrsRepr::Legacy(stage) => Self::with_stage(stage),
+            SrsRepr::Full {
+                stage,
+                interval,
+                ease,
+                due,
+                credit,
+            } => Self {
+                stage,
+                interval,
+                ease,
+                due,
+                credit,
+            },
+        }
+    }
+}
+
+impl Default for Srs {
+    fn default() -> Self {
+        Self::with_stage(0)
+    }
+}
+
+impl Srs {
+    /// A new state that is due right away
+    pub fn with_stage(stage: usize) -> Self {
+        Self {
+            stage,
+            interval: 0,
+            ease: STARTING_EASE,
+            due: 0,
+            credit: 0,
+        }
+    }
+    pub fn is_due(&self, now: i64) -> bool {
+        self.due <= now + LEEWAY
+    }
+    /// Update the state with the answers given for this item in one session,
+    /// `correct` is in percent of a typed answer. What doesn't add up to a
+    /// whole stage is kept for the next session, the schedule only moves
+    /// once a stage is earned or something is missed
+    pub fn review(&mut self, correct: usize, missed: usize, now: i64) {
+        let correct = self.credit as usize + correct;
+        self.credit = (correct % 100) as u32;
+        let correct = correct / 100;
+        if correct == 0 && missed == 0 {
+            return;
+        }
+        self.stage = (self.stage + correct).saturating_sub(PENALTY_STAGES * missed);
+        if missed == 0 {
+            self.interval = match self.interval {
+                0 => 1,
+                1 => 3,
+                interval => (interval as u64 * self.ease as u64).div_ceil(1000) as u32,
+            };
+        } else {
+            self.ease = self.ease.saturating_sub(EASE_STEP).max(MINIMUM_EASE);
+            self.interval = 1;
+        }
+        self.due = now + self.interval as i64 * DAY;
+    }
+}
@@ -0,0 +1,369 @@
+use std::collections::{BTreeMap, HashSet};
+
+use chrono::{Datelike, Days, Local, NaiveDate, TimeZone};
+use leptos::*;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::UnwrapThrowExt;
+
+use crate::{
+    invoke::{invoke_result, invokers},
+    learningkanji::QuestionType,
+    packs::LevelsInfo,
+};
+
+/// Weeks shown in the heatmap, a bit more than a year
+const HEATMAP_WEEKS: u64 = 53;
+/// Size of the box the learned items are drawn in
+const CHART_WIDTH: f64 = 300.0;
+const CHART_HEIGHT: f64 = 100.0;
+
+/// How an answer ended, an `AnswerStatus` without the soft errors
+/// since those only ask to try again
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub enum Outcome {
+    HardError,
+    Correct,
+    CorrectBitOff,
+}
+impl Outcome {
+    pub fn is_correct(&self) -> bool {
+        *self != Self::HardError
+    }
+}
+
+/// One answer given in a review, logged when the review is saved
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct Answer {
+    /// Unix timestamp in seconds
+    pub time: i64,
+    /// Counting from 0
+    pub level: usize,
+    pub identifier: String,
+    pub question_type: QuestionType,
+    pub outcome: Outcome,
+    /// Marked wrong and counted as correct by the user, the outcome says correct
+    #[serde(default)]
+    pub overridden: bool,
+}
+impl Answer {
+    /// Right without the user stepping in
+    pub fn is_right(&self) -> bool {
+        self.outcome.is_correct() && !self.overridden
+    }
+}
+pub type AnswersContext = (Signal<Vec<Answer>>, WriteSignal<Vec<Answer>>);
+
+/// The whole answer log, empty if it can't be read
+pub async fn load_answers() -> Vec<Answer> {
+    #[derive(Serialize)]
+    struct T {}
+    match invoke_result("load_answers", T {}).await {
+        Ok(answers) => answers,
+        Err(err) => {
+            logging::log!("failed to load answers: {err}");
+            Vec::new()
+        }
+    }
+}
+
+/// Adds answers to the log. The backend appends them to its file instead
+/// of saving the whole log with the progress
+pub fn log_answers(set_answers: WriteSignal<Vec<Answer>>, answers: Vec<Answer>) {
+    if answers.is_empty() {
+        return;
+    }
+    set_answers.update(|a| a.extend(answers.iter().cloned()));
+    spawn_local(async move {
+        #[derive(Serialize)]
+        struct T {
+            answers: Vec<Answer>,
+        }
+        invokers::<T, bool>("append_answers", T { answers })
+            .await
+            .unwrap_or_default();
+    });
+}
+
+/// Local day of a timestamp
+fn day(time: i64) -> Option<NaiveDate> {
+    Local
+        .timestamp_opt(time, 0)
+        .single()
+        .map(|a| a.date_naive())
+}
+
+#[derive(Clone, Copy, Default)]
+struct Accuracy {
+    /// Overridden answers are not in here
+    correct: usize,
+    overridden: usize,
+    total: usize,
+}
+impl Accuracy {
+    fn add(&mut self, answer: &Answer) {
+        self.total += 1;
+        if answer.overridden {
+            self.overridden += 1;
+        } else if answer.outcome.is_correct() {
+            self.correct += 1;
+        }
+    }
+    fn percent_of(&self, count: usize) -> usize {
+        match self.total {
+            0 => 0,
+            total => count * 100 / total,
+        }
+    }
+    fn percent(&self) -> usize {
+        self.percent_of(self.correct)
+    }
+    fn overridden_percent(&self) -> usize {
+        self.percent_of(self.overridden)
+    }
+}
+
+fn accuracy_by<K: Ord>(answers: &[Answer], key: impl Fn(&Answer) -> K) -> BTreeMap<K, Accuracy> {
+    let mut result = BTreeMap::<K, Accuracy>::new();
+    for answer in answers {
+        result.entry(key(answer)).or_default().add(answer);
+    }
+    result
+}
+
+fn daily_counts(answers: &[Answer]) -> BTreeMap<NaiveDate, usize> {
+    let mut counts = BTreeMap::new();
+    for day in answers.iter().filter_map(|a| day(a.time)) {
+        *counts.entry(day).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Current and longest run of days with answers. The current one
+/// is not broken until a whole day goes by without a review
+fn streaks(counts: &BTreeMap<NaiveDate, usize>, today: NaiveDate) -> (usize, usize) {
+    let mut longest = 0;
+    let mut run = 0;
+    let mut last = None::<NaiveDate>;
+    for day in counts.keys() {
+        run = match last {
+            Some(last) if last.succ_opt() == Some(*day) => run + 1,
+            _ => 1,
+        };
+        longest = longest.max(run);
+        last = Some(*day);
+    }
+    let current = match last {
+        Some(last) if last == today || last.succ_opt() == Some(today) => run,
+        _ => 0,
+    };
+    (current, longest)
+}
+
+/// Different items answered by the end of every day something new was seen
+fn learned_by_day(answers: &[Answer]) -> Vec<(NaiveDate, usize)> {
+    let mut sorted: Vec<&Answer> = answers.iter().collect();
+    sorted.sort_by_key(|a| a.time);
+    let mut seen = HashSet::new();
+    let mut result: Vec<(NaiveDate, usize)> = Vec::new();
+    for answer in sorted {
+        let Some(day) = day(answer.time) else {
+            continue;
+        };
+        if !seen.insert((
+            answer.question_type.mastery_type(),
+            answer.identifier.as_str(),
+        )) {
+            continue;
+        }
+        match result.last_mut() {
+            Some(last) if last.0 == day => last.1 = seen.len(),
+            _ => result.push((day, seen.len())),
+        }
+    }
+    result
+}
+
+/// Shade of a day in the heatmap, from 0 to 4
+fn heat(count: usize) -> usize {
+    match count {
+        0 => 0,
+        1..=20 => 1,
+        21..=50 => 2,
+        51..=100 => 3,
+        _ => 4,
+    }
+}
+
+#[component]
+fn AccuracyTable(title: &'static str, rows: Vec<(String, Accuracy)>) -> impl IntoView {
+    view! {
+        <section>
+            <h3>
+                {title}
+            </h3>
+            <table>
+                <tr>
+                    <th></th>
+                    <th>
+                        Answers
+                    </th>
+                    <th>
+                        Accuracy
+                    </th>
+                    <th>
+                        Overridden
+                    </th>
+                </tr>
+                {rows.into_iter().map(|(name, accuracy)| view! {
+                    <tr>
+                        <td>
+                            {name}
+                        </td>
+                        <td>
+                            {accuracy.total}
+                        </td>
+                        <td>
+                            {accuracy.percent()} "%"
+                        </td>
+                        <td>
+                            {accuracy.overridden_percent()} "%"
+                        </td>
+                    </tr>
+                }).collect_view()}
+            </table>
+        </section>
+    }
+}
+
+/// One column per week starting on monday, one square per day
+#[component]
+fn Heatmap(counts: BTreeMap<NaiveDate, usize>, today: NaiveDate) -> impl IntoView {
+    let back = 7 * (HEATMAP_WEEKS - 1) + today.weekday().num_days_from_monday() as u64;
+    let start = today.checked_sub_days(Days::new(back)).unwrap_or(today);
+    let days = start
+        .iter_days()
+        .take_while(|a| *a <= today)
+        .map(|day| {
+            let count = counts.get(&day).copied().unwrap_or_default();
+            view! {
+                <span class=format!("day heat{}", heat(count)) title=format!("{day}: {count} answers") />
+            }
+        })
+        .collect_view();
+    view! {
+        <section>
+            <h3>
+                Daily reviews
+            </h3>
+            <div class="heatmap">
+                {days}
+            </div>
+        </section>
+    }
+}
+
+#[component]
+fn LearnedChart(learned: Vec<(NaiveDate, usize)>, today: NaiveDate) -> impl IntoView {
+    let (Some(first), Some(total)) = (learned.first().map(|a| a.0), learned.last().map(|a| a.1))
+    else {
+        return ().into_view();
+    };
+    let days = (today - first).num_days().max(1) as f64;
+    let x = |day: NaiveDate| (day - first).num_days() as f64 / days * CHART_WIDTH;
+    let y = |count: usize| CHART_HEIGHT - count as f64 / total as f64 * CHART_HEIGHT;
+    // Flat until the next day something new was answered
+    let mut points = vec![format!("{},{}", x(first), CHART_HEIGHT)];
+    let mut last = 0;
+    for (day, count) in &learned {
+        points.push(format!("{},{}", x(*day), y(last)));
+        points.push(format!("{},{}", x(*day), y(*count)));
+        last = *count;
+    }
+    points.push(format!("{},{}", CHART_WIDTH, y(total)));
+    view! {
+        <section>
+            <h3>
+                Items learned
+            </h3>
+            <svg
+                class="learned"
+                xmlns="http://www.w3.org/2000/svg"
+                viewBox=format!("0 0 {CHART_WIDTH} {CHART_HEIGHT}")
+                preserveAspectRatio="none"
+            >
+                <polyline points=points.join(" ") />
+            </svg>
+            <div class="learned_axis">
+                <span>
+                    {first.to_string()}
+                </span>
+                <span>
+                    {total} " items"
+                </span>
+                <span>
+                    {today.to_string()}
+                </span>
+            </div>
+        </section>
+    }
+    .into_view()
+}
+
+#[component]
+pub fn Stats() -> impl IntoView {
+    let (answers, _) = use_context::<AnswersContext>().expect_throw("answers context");
+    let levels_info = use_context::<Signal<LevelsInfo>>().expect_throw("levels info context");
+    move || {
+        answers.with(|answers| {
+            if answers.is_empty() {
+                return view! {
+                    <section class="stats empty">
+                        "Nothing answered yet, the stats start with your next review"
+                    </section>
+                }
+                .into_view();
+            }
+            let today = Local::now().date_naive();
+            let counts = daily_counts(answers);
+            let (current, longest) = streaks(&counts, today);
+            let mut overall = Accuracy::default();
+            answers.iter().for_each(|a| overall.add(a));
+            let by_type = accuracy_by(answers, |a| a.question_type)
+                .into_iter()
+                .map(|(r#type, accuracy)| (r#type.label().to_string(), accuracy))
+                .collect();
+            let by_level = levels_info.with(|info| {
+                accuracy_by(answers, |a| a.level)
+                    .into_iter()
+                    .map(|(level, accuracy)| (info.level_name(level), accuracy))
+                    .collect()
+            });
+            view! {
+                <section class="stats">
+                    <div class="summary">
+                        <span>
+                            {answers.len()} " answers"
+                        </span>
+                        <span>
+                            {overall.percent()} "% right"
+                        </span>
+                        <span>
+                            {overall.overridden} " overridden (" {overall.overridden_percent()} "%)"
+                        </span>
+                        <span>
+                            "Current streak: " {current} " days"
+                        </span>
+                        <span>
+                            "Longest streak: " {longest} " days"
+                        </span>
+                    </div>
+                    <Heatmap counts today />
+                    <LearnedChart learned=learned_by_day(answers) today />
+                    <AccuracyTable title="By question" rows=by_type />
+                    <AccuracyTable title="By level" rows=by_level />
+                </section>
+            }
+            .into_view()
+        })
+    }
+}
@@ -0,0 +1,153 @@
+use std::{collections::HashMap, time::Duration};
+
+use leptos::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{invoke::invokers, writing::Point};
+
+/// Side of the box the KanjiVG paths are drawn in
+pub const STROKE_BOX: u32 = 109;
+/// Time each stroke takes when playing the whole kanji
+const STROKE_TIME: Duration = Duration::from_millis(700);
+
+/// One KanjiVG stroke, with the points along it worked out by the backend
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct Stroke {
+    pub path: String,
+    pub points: Vec<Point>,
+}
+
+/// Strokes in writing order, None without bundled data for it
+pub async fn get_kanji_strokes(kanji: String) -> Option<Vec<Stroke>> {
+    #[derive(Serialize)]
+    struct T {
+        kanji: String,
+    }
+    invokers("get_kanji_strokes", T { kanji })
+        .await
+        .ok()
+        .flatten()
+}
+
+/// False when the app was built without any stroke data
+pub async fn has_strokes() -> bool {
+    #[derive(Serialize)]
+    struct T {}
+    invokers("has_strokes", T {}).await.unwrap_or_default()
+}
+
+/// Paths of the strokes for every kanji asked that has stroke data
+pub async fn get_strokes_range(kanjis: Vec<String>) -> HashMap<String, Vec<String>> {
+    #[derive(Serialize)]
+    struct T {
+        kanjis: Vec<String>,
+    }
+    invokers("get_strokes_range", T { kanjis })
+        .await
+        .unwrap_or_default()
+}
+
+/// Numbered stroke order that can be stepped through or played.
+/// Nothing is shown for kanji without stroke data
+#[component]
+pub fn StrokeOrder(kanji: MaybeSignal<String>) -> impl IntoView {
+    let strokes = create_resource(move || kanji.get(), get_kanji_strokes);
+    move || {
+        strokes
+            .get()
+            .flatten()
+            .filter(|a| !a.is_empty())
+            .map(|strokes| view! { <StrokeDiagram strokes/> })
+    }
+}
+
+#[component]
+fn StrokeDiagram(strokes: Vec<Stroke>) -> impl IntoView {
+    let count = strokes.len();
+    // How many strokes are drawn
+    let step = create_rw_signal(count);
+    // The stroke being animated, the others are drawn at once
+    let drawing = create_rw_signal(None::<usize>);
+    let interval = store_value(None::<IntervalHandle>);
+    let stop = move || {
+        if let Some(handle) = interval.get_value() {
+            handle.clear();
+        }
+        interval.set_value(None);
+    };
+    on_cleanup(stop);
+    let go_to = move |new_step: usize| {
+        let new_step = new_step.min(count);
+        drawing.set((new_step > step.get_untracked()).then(|| new_step - 1));
+        step.set(new_step);
+    };
+    let play = move || {
+        stop();
+        go_to(0);
+        let handle = set_interval_with_handle(
+            move || {
+                let current = step.get_untracked();
+                if current >= count {
+                    stop();
+                } else {
+                    go_to(current + 1);
+                }
+            },
+            STROKE_TIME,
+        );
+        interval.set_value(handle.ok());
+    };
+    let strokes = strokes
+        .into_iter()
+        .enumerate()
+        .map(|(index, stroke)| {
+            let start = stroke.points.first().copied();
+            let class = move || match index < step.get() {
+                true if drawing.get() == Some(index) => "drawing",
+                true => "drawn",
+                false => "upcoming",
+            };
+            view! {
+                <path d=stroke.path pathLength="1" class=class />
+                {start.map(|(x, y)| view! {
+                    <text x={x - 4.0} y={y - 2.0} class=class>
+                        {index + 1}
+                    </text>
+                })}
+            }
+        })
+        .collect_view();
+    view! {
+        <section class="strokes">
+            <h3>
+                Stroke order
+            </h3>
+            <svg xmlns="http://www.w3.org/2000/svg" viewBox=format!("0 0 {STROKE_BOX} {STROKE_BOX}")>
+                {strokes}
+            </svg>
+            <div>
+                <button on:click=move |_| { stop(); go_to(0) }>
+                    "⏮"
+                </button>
+                <button on:click=move |_| { stop(); go_to(step.get_untracked().saturating_sub(1)) }>
+                    "◀"
+                </button>
+                <button on:click=move |_| { stop(); go_to(step.get_untracked() + 1) }>
+                    "▶"
+                </button>
+                <button on:click=move |_| { stop(); go_to(count) }>
+                    "⏭"
+                </button>
+                <button on:click=move |_| play()>
+                    Play
+                </button>
+                <span>
+                    {move || step.get()} / {count}
+                </span>
+            </div>
+            <a class="credit" href="https://kanjivg.tagaini.net" target="_blank">
+                "Strokes from KanjiVG, CC BY-SA 3.0"
+            </a>
+        </section>
+    }
+}
@@ -1,46 +0,0 @@
-use leptos::{component, view, IntoView};
-
-
-#[component]
-pub fn Svgs(value: String) -> impl IntoView {
-    match value.as_str() {
-        "gun" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-983c3b807cd38fbb8e00e82b40e5e5ce530ceb774886d1b328096869be07055a"><path d="M109.72 664.11 263.91 354.2H1094V664l-984.28.11z" style="fill:none"></path></clipPath><style>".c{fill:none;stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M192 501h748" class="c" style="clip-path:url(#a-983c3b807cd38fbb8e00e82b40e5e5ce530ceb774886d1b328096869be07055a)"></path><path d="M260 302c-28 155-94 262-200 383" class="c"></path></svg>},
-        "stick" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><path d="M500 60v880" style="fill:none;stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px"></path></svg>},
-        "leaf" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-e9ece07d1eeb919e4dc881404f4ad6f399f27b9cac49712d37d4f8c63bdf96b4"><path d="M-207 340H940v761H-207z" style="fill:none"></path></clipPath><style>".b{fill:none;stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M100 340h840" class="b"></path><path d="M60 820c133-88 343.16-249 374.58-480" class="b" style="clip-path:url(#a-e9ece07d1eeb919e4dc881404f4ad6f399f27b9cac49712d37d4f8c63bdf96b4)"></path></svg>},
-        "hat" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-695070ff05e913fce6cc8be1a62d35ad2459dd518a0868e80a7c06b95a2c9f90"><path d="M-182 300h1400v660H-182z" style="fill:none"></path></clipPath><style>".c{fill:none;stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><g style="clip-path:url(#a-695070ff05e913fce6cc8be1a62d35ad2459dd518a0868e80a7c06b95a2c9f90)"><path d="M60 700c160-80 360-260 440-400M940 700c-160-80-360-259-440-400" class="c"></path></g></svg>},
-        "triceratops" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><style>".a{fill:none;stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M500 730V330M780 690c64-80 120-171 160-260M220 690c-40-91-91-178-160-260" class="a"></path></svg>},
-        "beggar" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-35a210249e4b98a0c386f75ee6223f5037f71c33e85342f0b695f016936ac2fe"><path d="M1286.01-6.82 902.14 1340.24 49.66 1078.31 433.53-268.75 1286.01-6.82z" style="fill:none"></path></clipPath><style>".b{fill:none;stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M341 60 220 480" class="b"></path><g style="clip-path:url(#a-35a210249e4b98a0c386f75ee6223f5037f71c33e85342f0b695f016936ac2fe)"><path d="M241 420h519c-40 480-79 520-220 520-40 0-160-10-160-10M820 200H302" class="b"></path></g></svg>},
-        "horns" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><style>".a{fill:none;stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M740 640c49-72 130-213 160-280M280 640c-55-102-112-198-180-280" class="a"></path></svg>},
-        "spikes" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-3e1ba29381c0928cf33a185e97e88b2dbccd45b66560eefe7a1fc98c51ec6ade"><path d="M0 168h1000v432H0z" style="fill:none"></path></clipPath><style>".b{fill:none;stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M60 600h880" class="b"></path><g style="clip-path:url(#a-3e1ba29381c0928cf33a185e97e88b2dbccd45b66560eefe7a1fc98c51ec6ade)"><path d="M830 420c-21 53-65 130-96 180M174 420c18 32 29 54 42 80M400 400v200M600 400v200" class="b"></path></g></svg>},
-        "viking" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-4705c9c9eb65bc869816f56cc083cda709a611829dedd3e52bf810fcab190aca"><path d="M60 110h880v389H60z" style="fill:none"></path></clipPath><style>".b{fill:none;stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M60 640V499h880v141" class="b"></path><g style="clip-path:url(#a-4705c9c9eb65bc869816f56cc083cda709a611829dedd3e52bf810fcab190aca)"><path d="m720 499 54-119M540 499l-53-119M320 499l-59-119" class="b"></path></g></svg>},
-        "kick" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-23e43e5f3758c3f5fd8a0b8cbc5669a02491c3d4365853fbb7821600463a92b4"><path d="M-70 160h582v508H-70z" class="a"></path></clipPath><clipPath id="b-23e43e5f3758c3f5fd8a0b8cbc5669a02491c3d4365853fbb7821600463a92b4"><path d="M1000 660V140H500v293.82L759.53 660H1000z" class="a"></path></clipPath><style>".a,.b{fill:none}.b{stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M940 700c-200-60-360-158-480-340M60 700c160-27 280-60 400-100" class="b"></path><path d="M220 360v308.25" class="b" style="clip-path:url(#a-23e43e5f3758c3f5fd8a0b8cbc5669a02491c3d4365853fbb7821600463a92b4)"></path><path d="M820 360c-69 75-171 156.86-200 178.43" class="b" style="clip-path:url(#b-23e43e5f3758c3f5fd8a0b8cbc5669a02491c3d4365853fbb7821600463a92b4)"></path></svg>},
-        "cape" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><path d="M100 100h800v720q0 120-120 120H660" style="fill:none;stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px"></path></svg>},
-        "cleat" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-e40437d5bc585c5d5256c1316ca4ed8cbe1b9d4ca006e3e056feb656ca738188"><path d="M100 470v140h880V390H860c-200 60-360 80-740 80" style="fill:none"></path></clipPath><style>".b{fill:none;stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M120 470c380 0 540-20 740-80" class="b"></path><g style="clip-path:url(#a-e40437d5bc585c5d5256c1316ca4ed8cbe1b9d4ca006e3e056feb656ca738188)"><path d="M814 485c-27 66-53 136-79 185M540 670l-71.02-214.17M291 670l-71-201" class="b"></path></g></svg>},
-        "gladiator" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-fba193da7af557c70f33647db1e95c85945af4bffaf33d8dbe69f2ae682eefa2"><path d="M1131 940H420V381l711-1v560z" class="a"></path></clipPath><clipPath id="b-fba193da7af557c70f33647db1e95c85945af4bffaf33d8dbe69f2ae682eefa2"><path d="M60 42h880v338H60z" class="a"></path></clipPath><style>".a,.b{fill:none}.b{stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M80 540h840" class="b"></path><path d="M500 220c59 240 200 429 420 560" class="b" style="clip-path:url(#a-fba193da7af557c70f33647db1e95c85945af4bffaf33d8dbe69f2ae682eefa2)"></path><path d="M500 220C441 460 300 649 80 780M140 380h720" class="b"></path><g style="clip-path:url(#b-fba193da7af557c70f33647db1e95c85945af4bffaf33d8dbe69f2ae682eefa2)"><path d="M620 380a1290.62 1290.62 0 0 0 91-140M330 380c-35-80-74-140-74-140" class="b"></path></g></svg>},
-        "pope" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-64ae67f8776aa4a73ec21c9eb3d5f6628c625b8401fecd3af5223c8f538f8781"><path d="M179.5 340.5h640v560h-640z" class="a"></path></clipPath><clipPath id="b-64ae67f8776aa4a73ec21c9eb3d5f6628c625b8401fecd3af5223c8f538f8781"><path d="M179.5-215.5h640v556h-640z" class="a"></path></clipPath><style>".a,.b{fill:none}.b{stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M180 340h640v560H180zM60 180h880" class="b"></path><g style="clip-path:url(#a-64ae67f8776aa4a73ec21c9eb3d5f6628c625b8401fecd3af5223c8f538f8781)"><path d="M820 527H180M820 713H180" class="b"></path></g><path d="M500 60v280" class="b" style="clip-path:url(#b-64ae67f8776aa4a73ec21c9eb3d5f6628c625b8401fecd3af5223c8f538f8781)"></path></svg>},
-        "spring" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-10f3cfcb3eb89cedf74b798ee631794874c7c0b344874c7d7f723c5caa790441"><path d="M140 400h1163v700H140z" style="fill:none"></path></clipPath><style>".b{fill:none;stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M100 560h800M140 400h720M140 240h720M500 160C460 400 299 680 60 840" class="b"></path><path d="M500 160c40 240 201 520 440 680" class="b" style="clip-path:url(#a-10f3cfcb3eb89cedf74b798ee631794874c7c0b344874c7d7f723c5caa790441)"></path></svg>},
-        "squid" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-0f097eb9cbef6f7888084618d982864cbddf10b39a89f6e2af94e0755c15ad73"><path d="M940 1081H460V813.98L516 500h424v581z" class="a"></path></clipPath><clipPath id="b-0f097eb9cbef6f7888084618d982864cbddf10b39a89f6e2af94e0755c15ad73"><path d="M0 290h1000v950H0z" class="a"></path></clipPath><clipPath id="c-0f097eb9cbef6f7888084618d982864cbddf10b39a89f6e2af94e0755c15ad73"><path d="M0 48h1000v452H0z" class="a"></path></clipPath><style>".a,.b{fill:none}.b{stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M280 440h440v200H280zM360 290h280" class="b"></path><path d="M740 940c-120-64-200-160-246.5-308.86" class="b" style="clip-path:url(#a-0f097eb9cbef6f7888084618d982864cbddf10b39a89f6e2af94e0755c15ad73)"></path><path d="M260 940c160-99 240-200 240-400V290" class="b" style="clip-path:url(#b-0f097eb9cbef6f7888084618d982864cbddf10b39a89f6e2af94e0755c15ad73)"></path><path d="M260 300c93.78-62.52 160-135 240-240 80 105 146.22 177.48 240 240" class="b" style="clip-path:url(#c-0f097eb9cbef6f7888084618d982864cbddf10b39a89f6e2af94e0755c15ad73)"></path></svg>},
-        "yurt" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-9397ed868e310c8eb8fdf1c69677024945a2026247f97746dfcb5aba747c747f"><path d="M140-149h800v309H140z" class="a"></path></clipPath><clipPath id="b-9397ed868e310c8eb8fdf1c69677024945a2026247f97746dfcb5aba747c747f"><path d="M140 160h1072v362H140z" class="a"></path></clipPath><style>".a,.b{fill:none}.b{stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M60 940c80-240 80-440 80-600V160l800-1" class="b"></path><path d="M380 240v240h360V240" class="b"></path><path d="M540 60v99" class="b" style="clip-path:url(#a-9397ed868e310c8eb8fdf1c69677024945a2026247f97746dfcb5aba747c747f)"></path><path d="M140 340h800" class="b" style="clip-path:url(#b-9397ed868e310c8eb8fdf1c69677024945a2026247f97746dfcb5aba747c747f)"></path></svg>},
-        "chinese" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-aeab524bcfa5e7aedc05d37f641948dbfe8334d4f9af2969c29dc4af0b9fa04b"><path d="M980 1108H420V888.5L511.76 620H980v488z" class="a"></path></clipPath><clipPath id="b-aeab524bcfa5e7aedc05d37f641948dbfe8334d4f9af2969c29dc4af0b9fa04b"><path d="M0 300h1000v881H0z" class="a"></path></clipPath><clipPath id="c-aeab524bcfa5e7aedc05d37f641948dbfe8334d4f9af2969c29dc4af0b9fa04b"><path d="M260-129h480v429H260z" class="a"></path></clipPath><style>".a,.b{fill:none}.b{stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M220 150h600M260 300h480v160H260zM242 600h520M820 740H180" class="b"></path><path d="M820 940C500 834 481 709 481 709" class="b" style="clip-path:url(#a-aeab524bcfa5e7aedc05d37f641948dbfe8334d4f9af2969c29dc4af0b9fa04b)"></path><path d="M180 940c320-120 320-120 320-640" class="b" style="clip-path:url(#b-aeab524bcfa5e7aedc05d37f641948dbfe8334d4f9af2969c29dc4af0b9fa04b)"></path><g style="clip-path:url(#c-aeab524bcfa5e7aedc05d37f641948dbfe8334d4f9af2969c29dc4af0b9fa04b)"><path d="M380 60v240M620 60v240" class="b"></path></g></svg>},
-        "bear" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-d772b6a4cd42e180352ddd6e0427cd658f11aaf1e74c42eb7aff10fcd6a8ec7d"><path d="M159.5 559.5h680v642h-680z" class="a"></path></clipPath><clipPath id="b-d772b6a4cd42e180352ddd6e0427cd658f11aaf1e74c42eb7aff10fcd6a8ec7d"><path d="M159.5 379.5h1014v822h-1014z" class="a"></path></clipPath><style>".a,.c{fill:none}.c{stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M840 880H160" class="c" style="clip-path:url(#a-d772b6a4cd42e180352ddd6e0427cd658f11aaf1e74c42eb7aff10fcd6a8ec7d)"></path><path d="M160 560h680v380" class="c" style="clip-path:url(#b-d772b6a4cd42e180352ddd6e0427cd658f11aaf1e74c42eb7aff10fcd6a8ec7d)"></path><path d="M160 380h640V100H160v840" class="c"></path></svg>},
-        "blackjack" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-b563319355d843b076e35e774168288a789b4b116abbc584194da9aae60c77d8"><path d="M61 0h879v819H61z" style="fill:none"></path></clipPath><style>".b{fill:none;stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M60.5 819.5h880M901 420H101" class="b"></path><g style="clip-path:url(#a-b563319355d843b076e35e774168288a789b4b116abbc584194da9aae60c77d8)"><path d="M301 220v599.5M701 220v599.5" class="b"></path></g></svg>},
-        "trash" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-b7f1fe9516d9e5d9b2ce38d8689ef0d2d6609e324a6584257fb91b0f66009563"><path d="m-90 480 875-19v402l-875 19V480z" class="a"></path></clipPath><clipPath id="b-b7f1fe9516d9e5d9b2ce38d8689ef0d2d6609e324a6584257fb91b0f66009563"><path d="M-168 360h1336v335H-168z" class="a"></path></clipPath><clipPath id="c-b7f1fe9516d9e5d9b2ce38d8689ef0d2d6609e324a6584257fb91b0f66009563"><path d="M300.5 78.5h400v282h-400z" class="a"></path></clipPath><style>".a,.b{fill:none}.b{stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M650 480c62 66 142 160 200 240" class="b"></path><path d="M140 700c252.77-11.8 441.47-30.7 645-57" class="b" style="clip-path:url(#a-b7f1fe9516d9e5d9b2ce38d8689ef0d2d6609e324a6584257fb91b0f66009563)"></path><path d="M450 360 280 695" class="b" style="clip-path:url(#b-b7f1fe9516d9e5d9b2ce38d8689ef0d2d6609e324a6584257fb91b0f66009563)"></path><path d="M100 360h800" class="b"></path><path d="M500 260v100" class="b" style="clip-path:url(#c-b7f1fe9516d9e5d9b2ce38d8689ef0d2d6609e324a6584257fb91b0f66009563)"></path></svg>},
-        "tofu" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-e06919525fd0ee1686f6272c10a3bc6a2aff4a69256de7e49d30ad528ec857b3"><path d="M1000 1126H180V372.61l452.29-269.55L1000 100v1026z" class="a"></path></clipPath><clipPath id="b-e06919525fd0ee1686f6272c10a3bc6a2aff4a69256de7e49d30ad528ec857b3"><path d="M980 140v480H598.11L500 331.83V140h480z" class="a"></path></clipPath><style>".a,.b{fill:none}.b{stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M220 340c131.54-60.65 320-169 440-280" class="b"></path><g style="clip-path:url(#a-e06919525fd0ee1686f6272c10a3bc6a2aff4a69256de7e49d30ad528ec857b3)"><path d="M340 940V279.44M800 900C640 740 511 487 500 183" class="b"></path></g><path d="M562 513c78.34-50.54 178.39-127.43 238-193" class="b" style="clip-path:url(#b-e06919525fd0ee1686f6272c10a3bc6a2aff4a69256de7e49d30ad528ec857b3)"></path></svg>},
-        "creeper" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><style>".a{fill:none;stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M100 300h800M160 460h680v280H160z" class="a"></path></svg>},
-        "bar" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><style>".a{fill:none;stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M440 60c37 840 220 880 260 880s73-40 80-120" class="a"></path><path d="M260 940c160-60 306-151 433-280M280 580l480-60M320 400l380-40M300 230l420-40M600 60a514 514 0 0 1 40 40" class="a"></path></svg>},
-        "grass" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-dad8020126e3ee97560c625a8ead32a00d13c62395f1e1005653bce26cc1b400"><path d="M-95 480h395v80H-95z" class="a"></path></clipPath><clipPath id="b-dad8020126e3ee97560c625a8ead32a00d13c62395f1e1005653bce26cc1b400"><path d="M300 460h360v100H300z" class="a"></path></clipPath><clipPath id="c-dad8020126e3ee97560c625a8ead32a00d13c62395f1e1005653bce26cc1b400"><path d="M700 320h387v240H700z" class="a"></path></clipPath><style>".a,.c{fill:none}.c{stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="m91 480 69 160" class="c" style="clip-path:url(#a-dad8020126e3ee97560c625a8ead32a00d13c62395f1e1005653bce26cc1b400)"></path><path d="m473 460 67 180" class="c" style="clip-path:url(#b-dad8020126e3ee97560c625a8ead32a00d13c62395f1e1005653bce26cc1b400)"></path><path d="m888 470-68 170" class="c" style="clip-path:url(#c-dad8020126e3ee97560c625a8ead32a00d13c62395f1e1005653bce26cc1b400)"></path></svg>},
-        "saw" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-b3020f89eadc16bad8e7ff1a107f2112e0dc1833d647c481ddc369287b5172d9"><path d="M20 300h400v350H20z" style="fill:none"></path></clipPath><style>".b{fill:none;stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M60 700c98-22.65 218.27-63.67 300-100M360 300H100M420 720c80-120 80-200 80-440h280v360c0 40 40 80 80 80s80-40 80-80v-80M680 600l-80-120" class="b"></path><path d="M220 653V300" class="b" style="clip-path:url(#a-b3020f89eadc16bad8e7ff1a107f2112e0dc1833d647c481ddc369287b5172d9)"></path></svg>},
-        "zombie" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-f055a3eeb4e4ab15e298990e61f68de553f4698d07e33319fa8f592e0c519bf2"><path d="M-84 620h1164v437H-84z" class="a"></path></clipPath><clipPath id="b-f055a3eeb4e4ab15e298990e61f68de553f4698d07e33319fa8f592e0c519bf2"><path d="M980 500v400l-200 1.11-160-110.58V500h360z" class="a"></path></clipPath><clipPath id="c-f055a3eeb4e4ab15e298990e61f68de553f4698d07e33319fa8f592e0c519bf2"><path d="M180.5-115.5h640v435h-640z" class="a"></path></clipPath><style>".a,.b{fill:none}.b{stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M180 320h640M220 160h560M500 940V620M220 460h560v160H220z" class="b"></path><g style="clip-path:url(#a-f055a3eeb4e4ab15e298990e61f68de553f4698d07e33319fa8f592e0c519bf2)"><path d="M500 620c-80 120-229 200-320 240M860 920c-160-54-280-140-360-300" class="b"></path></g><path d="M680 832c49.23-46.15 80.65-81.7 119-132" class="b" style="clip-path:url(#b-f055a3eeb4e4ab15e298990e61f68de553f4698d07e33319fa8f592e0c519bf2)"></path><path d="M500 60v260" class="b" style="clip-path:url(#c-f055a3eeb4e4ab15e298990e61f68de553f4698d07e33319fa8f592e0c519bf2)"></path></svg>},
-        "explosion" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><style>".a{fill:none;stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M180 740c102.95-49.27 168.59-98.35 240-160M820 740c-83.81-66.17-140.34-107.8-240-160M800 280c-67.33 59.43-127.15 94.4-220 140M420 420c-64.42-54.25-140-100-220-140" class="a"></path></svg>},
-        "morning" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-0232d1827dabeb4d50fa8eb66a34681c70fa0bb2cfb9737bba07a4c6a143dc16"><path d="M340.5 319.5h320v320h-320z" class="a"></path></clipPath><clipPath id="b-0232d1827dabeb4d50fa8eb66a34681c70fa0bb2cfb9737bba07a4c6a143dc16"><path d="M340.5 639.5h320v501h-320z" class="a"></path></clipPath><clipPath id="c-0232d1827dabeb4d50fa8eb66a34681c70fa0bb2cfb9737bba07a4c6a143dc16"><path d="M340.5-118.5h320v438h-320z" class="a"></path></clipPath><style>".a,.b{fill:none}.b{stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M320 160h360M320 800h360M340 320h320v320H340z" class="b"></path><path d="M660 480H340" class="b" style="clip-path:url(#a-0232d1827dabeb4d50fa8eb66a34681c70fa0bb2cfb9737bba07a4c6a143dc16)"></path><path d="M500 940V640" class="b" style="clip-path:url(#b-0232d1827dabeb4d50fa8eb66a34681c70fa0bb2cfb9737bba07a4c6a143dc16)"></path><path d="M500 320V60" class="b" style="clip-path:url(#c-0232d1827dabeb4d50fa8eb66a34681c70fa0bb2cfb9737bba07a4c6a143dc16)"></path></svg>},
-        "death star" => view! { <svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-15f9314ebc2260a4b70bca2be42a91d9c382d259ff17ec2abdf48b122f09be0a"><path d="M0 39h1000v421H0z" class="a"></path></clipPath><clipPath id="b-15f9314ebc2260a4b70bca2be42a91d9c382d259ff17ec2abdf48b122f09be0a"><path d="M240 420h220v520H240z" class="a"></path></clipPath><style>".a,.b{fill:none}.b{stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M240 940V420h220v440c0 40-40 80-80 80M340 300h320" class="b"></path><path d="M780 300C676 244.9 580 169 500 60c-80 109-176 184.9-280 240" class="b" style="clip-path:url(#a-15f9314ebc2260a4b70bca2be42a91d9c382d259ff17ec2abdf48b122f09be0a)"></path><path d="M600 780V460M760 420v440c0 40-40 80-80 80h-60" class="b"></path><g style="clip-path:url(#b-15f9314ebc2260a4b70bca2be42a91d9c382d259ff17ec2abdf48b122f09be0a)"><path d="M460 740H240M460 580H240" class="b"></path></g></svg> },
-        "comb" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-643fe67c144743403b0866b6ea214beef85270bbc5b517ff9278d735eed41514"><path d="M180 140h480v800H180z" style="fill:none"></path></clipPath><style>".b{fill:none;stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M380 940V130c160-23 260-70 260-70" class="b"></path><g style="clip-path:url(#a-643fe67c144743403b0866b6ea214beef85270bbc5b517ff9278d735eed41514)"><path d="M380 300h280M380 520h280M340 766c168.38-14 320-46 320-46" class="b"></path></g></svg>},
-        "hills" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-20ac97d9fd5ce021e57e7127b199869354ce4b74f82b1a6b77a15d418ec6ae09"><path d="M1000 780H445.92L0 351.1V0h1000v780z" style="fill:none"></path></clipPath><style>".b{fill:none;stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M80 840c83.26-99.08 140-160 180-360 40 200 80 360 680 360" class="b"></path><path d="M140 160h700C680 387 498.52 538.31 317 656" class="b" style="clip-path:url(#a-20ac97d9fd5ce021e57e7127b199869354ce4b74f82b1a6b77a15d418ec6ae09)"></path></svg>},
-        "elf" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-666262f1443b55e3d04c9631a8310b59df26bd39d7546a90c9b5b70ccc9670d6"><path d="M360 100h280v640H360z" class="a"></path></clipPath><clipPath id="b-666262f1443b55e3d04c9631a8310b59df26bd39d7546a90c9b5b70ccc9670d6"><path d="M100 660h540v280H100z" class="a"></path></clipPath><style>".a,.b{fill:none}.b{stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M340 100h320M300 260h400M360 260v520M640 260v680" class="b"></path><g style="clip-path:url(#a-666262f1443b55e3d04c9631a8310b59df26bd39d7546a90c9b5b70ccc9670d6)"><path d="M500 100v160M640 430H360M640 600H360" class="b"></path></g><path d="M280 820c180-28 360-90 360-90" class="b" style="clip-path:url(#b-666262f1443b55e3d04c9631a8310b59df26bd39d7546a90c9b5b70ccc9670d6)"></path></svg>},
-        "coral" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-3bf6938d0c0e379b7bd8f73d5b9dfb8c8fa3abf0def677028182a908b7034110"><path d="m342 270 181 240v420h-640V270h459z" class="a"></path></clipPath><clipPath id="b-3bf6938d0c0e379b7bd8f73d5b9dfb8c8fa3abf0def677028182a908b7034110"><path d="M500 510V310H100V30h800v480H500z" class="a"></path></clipPath><clipPath id="c-3bf6938d0c0e379b7bd8f73d5b9dfb8c8fa3abf0def677028182a908b7034110"><path d="M420 336h676v614H420z" class="a"></path></clipPath><clipPath id="d-3bf6938d0c0e379b7bd8f73d5b9dfb8c8fa3abf0def677028182a908b7034110"><path d="M673 150h355v510H673z" class="a"></path></clipPath><style>".a,.b{fill:none}.b{stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M220 830h560" class="b"></path><path d="M100 790c200-178 279-440 279-440H120" class="b" style="clip-path:url(#a-3bf6938d0c0e379b7bd8f73d5b9dfb8c8fa3abf0def677028182a908b7034110)"></path><path d="M500 370v260c0 40-40 80-80 80h-40" class="b"></path><path d="M260 170h480C636 316 500 390 500 390" class="b" style="clip-path:url(#b-3bf6938d0c0e379b7bd8f73d5b9dfb8c8fa3abf0def677028182a908b7034110)"></path><path d="M900 790C652 608 580 336 580 336" class="b" style="clip-path:url(#c-3bf6938d0c0e379b7bd8f73d5b9dfb8c8fa3abf0def677028182a908b7034110)"></path><path d="M673 540c98.89-83.81 187-210 187-210" class="b" style="clip-path:url(#d-3bf6938d0c0e379b7bd8f73d5b9dfb8c8fa3abf0def677028182a908b7034110)"></path></svg>},
-        "cactus" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-f99790d86330128105497ed6d92278718328da59e2c0957f6711fe0c3917d7c5"><path d="M100 60h800v720H100z" class="a"></path></clipPath><clipPath id="b-f99790d86330128105497ed6d92278718328da59e2c0957f6711fe0c3917d7c5"><path d="M60 100h340v680H60z" class="a"></path></clipPath><clipPath id="c-f99790d86330128105497ed6d92278718328da59e2c0957f6711fe0c3917d7c5"><path d="M600 100h340v680H600z" class="a"></path></clipPath><style>".a,.b{fill:none}.b{stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M100 780h800" class="b"></path><g style="clip-path:url(#a-f99790d86330128105497ed6d92278718328da59e2c0957f6711fe0c3917d7c5)"><path d="M600 780V220M400 780V220" class="b"></path></g><path d="M400 560H200V300" class="b" style="clip-path:url(#b-f99790d86330128105497ed6d92278718328da59e2c0957f6711fe0c3917d7c5)"></path><path d="M600 560h200V300" class="b" style="clip-path:url(#c-f99790d86330128105497ed6d92278718328da59e2c0957f6711fe0c3917d7c5)"></path></svg>},
-        "satellite" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-a8e3a61ecc72ba1cbcaa4aa891421cb1a7d687ff2ef0bae449d2ffcd473f844f"><path d="M100 79h800v341H100z" class="a"></path></clipPath><clipPath id="b-a8e3a61ecc72ba1cbcaa4aa891421cb1a7d687ff2ef0bae449d2ffcd473f844f"><path d="M260 420h480v460H260z" class="a"></path></clipPath><clipPath id="c-a8e3a61ecc72ba1cbcaa4aa891421cb1a7d687ff2ef0bae449d2ffcd473f844f"><path d="M260 740h480v275H260z" class="a"></path></clipPath><style>".a,.b{fill:none}.b{stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="M260 940V740M740 940V740M260 420h480M240 620h520M260 140c280-16 480-80 480-80" class="b"></path><g style="clip-path:url(#a-a8e3a61ecc72ba1cbcaa4aa891421cb1a7d687ff2ef0bae449d2ffcd473f844f)"><path d="M620 420c80-135 120-240 120-240M523 340c-13-70-43-140-43-140M328 340c-18-52-43-111-43-111" class="b"></path></g><path d="M500 880V420" class="b" style="clip-path:url(#b-a8e3a61ecc72ba1cbcaa4aa891421cb1a7d687ff2ef0bae449d2ffcd473f844f)"></path><path d="M260 880h480" class="b" style="clip-path:url(#c-a8e3a61ecc72ba1cbcaa4aa891421cb1a7d687ff2ef0bae449d2ffcd473f844f)"></path></svg>},
-        "psychopath" => view! {<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000" style="width: 100%; display: block;"><defs><clipPath id="a-5de2f0277af69b3960cf7455670a8a664649d9a00a6e55d64cba7befecb4a9a8"><path d="M141 471.9h460V940H141z" style="fill:none"></path></clipPath><style>".b{fill:none;stroke:var(--color-text, #000);stroke-linecap:square;stroke-miterlimit:2;stroke-width:68px}"</style></defs><path d="m541 266.59-1 255.76H140l1-258.1" class="b"></path><path d="M60 290.06V100h880v190.06" class="b"></path><path d="M219 427.32C307 365 394 279 461 195" class="b"></path><path d="M461 427.32C380 343 300 260 221 195M479 317.04l24 23.46M347 441.4l24 23.46M189 293.58l26 21.11M329.92 159.95l24 23.46M141 615v169c0 61-6 112.63 182 112.63 176 0 202-23.46 218-91.51" class="b"></path><path d="M479 662c-106 44.58-239.5 77.83-338 82.12" class="b" style="clip-path:url(#a-5de2f0277af69b3960cf7455670a8a664649d9a00a6e55d64cba7befecb4a9a8)"></path><path d="M640 360c100-49 159-139 159-139M640 620c132-67.3 230-180 230-180M940 660S809.84 808.07 640 880" class="b"></path></svg>},
-        _ => view!{ <svg> </svg> }
-    }.into_view()
-}
\ No newline at end of file
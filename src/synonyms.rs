@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+
+use leptos::*;
+use serde::{Deserialize, Serialize};
+use wana_kana::ConvertJapanese;
+use wasm_bindgen::UnwrapThrowExt;
+use web_sys::SubmitEvent;
+
+use crate::home::MasteryType;
+
+pub type SynonymsContext = (Signal<Synonyms>, WriteSignal<Synonyms>);
+
+/// Answers the user also wants accepted for one item
+#[derive(Clone, PartialEq, Eq, Default, Deserialize, Serialize, Debug)]
+pub struct ItemSynonyms {
+    #[serde(default)]
+    pub meanings: Vec<String>,
+    /// Kept in hiragana
+    #[serde(default)]
+    pub readings: Vec<String>,
+}
+
+/// Keyed like `Viewed`, the meaning for radicals and the character otherwise
+#[derive(Clone, PartialEq, Eq, Default, Deserialize, Serialize, Debug)]
+pub struct Synonyms {
+    #[serde(default)]
+    pub kanjis: HashMap<String, ItemSynonyms>,
+    #[serde(default)]
+    pub radicals: HashMap<String, ItemSynonyms>,
+    #[serde(default)]
+    pub vocabs: HashMap<String, ItemSynonyms>,
+}
+impl Synonyms {
+    pub fn of_type(&self, r#type: MasteryType) -> &HashMap<String, ItemSynonyms> {
+        match r#type {
+            MasteryType::Kanji => &self.kanjis,
+            MasteryType::Radical => &self.radicals,
+            MasteryType::Vocabulary => &self.vocabs,
+        }
+    }
+    pub fn of_type_mut(&mut self, r#type: MasteryType) -> &mut HashMap<String, ItemSynonyms> {
+        match r#type {
+            MasteryType::Kanji => &mut self.kanjis,
+            MasteryType::Radical => &mut self.radicals,
+            MasteryType::Vocabulary => &mut self.vocabs,
+        }
+    }
+    pub fn get(&self, r#type: MasteryType, identifier: &str) -> Option<&ItemSynonyms> {
+        self.of_type(r#type).get(identifier)
+    }
+}
+
+#[component]
+pub fn SynonymsEditor(
+    kind: MasteryType,
+    #[prop(into)] identifier: MaybeSignal<String>,
+    /// Radicals only have a meaning
+    #[prop(optional)]
+    readings: bool,
+) -> impl IntoView {
+    let (synonyms, set_synonyms) =
+        use_context::<SynonymsContext>().expect_throw("synonyms context");
+    let new_synonym = create_rw_signal(String::new());
+    let is_reading = create_rw_signal(false);
+    let current = move || {
+        synonyms.with(|synonyms| {
+            identifier.with(|identifier| {
+                synonyms
+                    .get(kind, identifier)
+                    .cloned()
+                    .unwrap_or_default()
+            })
+        })
+    };
+    let add = move |ev: SubmitEvent| {
+        ev.prevent_default();
+        let value = new_synonym.get().trim().to_lowercase();
+        if value.is_empty() {
+            return;
+        }
+        let is_reading = is_reading.get();
+        let value = if is_reading {
+            value.to_hiragana()
+        } else {
+            value
+        };
+        set_synonyms.update(|synonyms| {
+            let item = synonyms
+                .of_type_mut(kind)
+                .entry(identifier.get_untracked())
+                .or_default();
+            let list = if is_reading {
+                &mut item.readings
+            } else {
+                &mut item.meanings
+            };
+            if !list.contains(&value) {
+                list.push(value);
+            }
+        });
+        new_synonym.set(String::new());
+    };
+    let remove = move |value: String, is_reading: bool| {
+        set_synonyms.update(|synonyms| {
+            let items = synonyms.of_type_mut(kind);
+            let identifier = identifier.get_untracked();
+            let Some(item) = items.get_mut(&identifier) else {
+                return;
+            };
+            if is_reading {
+                item.readings.retain(|a| *a != value);
+            } else {
+                item.meanings.retain(|a| *a != value);
+            }
+            if item.meanings.is_empty() && item.readings.is_empty() {
+                items.remove(&identifier);
+            }
+        });
+    };
+    view! {
+        <section class="synonyms">
+            <h3>
+                Your synonyms
+            </h3>
+            <div>
+                {move || {
+                    let current = current();
+                    current
+                        .meanings
+                        .into_iter()
+                        .map(|a| (a, false))
+                        .chain(current.readings.into_iter().map(|a| (a, true)))
+                        .map(|(value, is_reading)| {
+                            let removed = value.clone();
+                            view! {
+                                <span class:reading=is_reading>
+                                    {value}
+                                    <button on:click=move |_| remove(removed.clone(), is_reading)>
+                                        x
+                                    </button>
+                                </span>
+                            }
+                        })
+                        .collect_view()
+                }}
+            </div>
+            <form on:submit=add>
+                <input
+                    type="text"
+                    placeholder="Add a synonym"
+                    prop:value=move || new_synonym.get()
+                    on:input=move |ev| new_synonym.set(event_target_value(&ev))
+                />
+                {readings.then(|| view! {
+                    <select on:change=move |ev| is_reading.set(event_target_value(&ev) == "reading")>
+                        <option value="meaning" selected=move || !is_reading.get()>Meaning</option>
+                        <option value="reading" selected=move || is_reading.get()>Reading</option>
+                    </select>
+                })}
+                <button>
+                    Add
+                </button>
+            </form>
+        </section>
+    }
+}
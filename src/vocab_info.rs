@@ -1,8 +1,12 @@
 use crate::button_link::ButtonLink;
 use crate::has_acess_logic;
+use crate::home::MasteryType;
 use crate::invoke::get_kanjis;
 use crate::invoke::invokers;
-use crate::utils::local_set;
+use crate::packs::LevelsInfo;
+use crate::srs::Srs;
+use crate::synonyms::SynonymsEditor;
+use crate::LastLearnedContext;
 use crate::ViewedContext;
 use crate::LIMIT;
 use chrono::Datelike;
@@ -36,6 +40,9 @@ pub fn VocabInfo() -> impl IntoView {
     let params = use_params::<VocabParams>();
     let (times_learned, set_times_learned) =
         use_context::<(Signal<u32>, WriteSignal<u32>)>().expect_throw("times learned context");
+    let LastLearnedContext(_, set_last_learned) =
+        use_context::<LastLearnedContext>().expect_throw("last learned context");
+    let levels_info = use_context::<Signal<LevelsInfo>>().expect_throw("levels info context");
     let vocab = move || match params.get() {
         Ok(a) => a.vocab,
         _ => None,
@@ -60,17 +67,17 @@ pub fn VocabInfo() -> impl IntoView {
         },
     );
     let has_acess = create_resource(
-        move || (viewed.get(), info.get()),
-        move |(viewed, info)| async move {
+        move || (viewed.get(), info.get(), levels_info.get()),
+        move |(viewed, info, levels_info)| async move {
             let level = match info {
                 Some(Some(a)) if a.wk_level.is_some() => a.wk_level.unwrap() - 1,
                 _ => return false,
             };
-            if level == 0 {
-                let Some(kanjis) = get_kanjis(1).await else {
+            if levels_info.starts_pack(level as usize) {
+                let Some(kanjis) = get_kanjis(level as usize + 1).await else {
                     return false;
                 };
-                has_acess_logic(1, &Some(kanjis), &viewed)
+                has_acess_logic(level as usize + 1, &Some(kanjis), &viewed)
                     .kanji
                     .partial_or_more()
             } else {
@@ -113,11 +120,11 @@ pub fn VocabInfo() -> impl IntoView {
                 return;
             }
             let now = chrono::offset::Local::now();
-            local_set("last_learned".to_string(), now.date_naive().day());
+            set_last_learned.set(now.date_naive().day());
             set_times_learned.update(|a| {
                 *a = *a + 1;
             });
-            level_viewed.vocabs.push((vocab, 0));
+            level_viewed.vocabs.push((vocab, Srs::default()));
         })
     };
     let has_learned = create_memo(move |_| {
@@ -206,6 +213,10 @@ pub fn VocabInfo() -> impl IntoView {
                 }
             })}
 
+            <SynonymsEditor
+                kind=MasteryType::Vocabulary
+                identifier=Signal::derive(move || vocab().unwrap_or_default()) readings=true
+            />
             {move || vocab().map(|vocab| view! {
                 <section class="other_sources">
                     <h3>
@@ -0,0 +1,279 @@
+use leptos::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{invoke::invokers, strokes::STROKE_BOX};
+
+/// Misses on the same stroke before it gets shown
+const MISSES_BEFORE_HINT: usize = 2;
+
+pub type Point = (f64, f64);
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Deserialize)]
+pub enum StrokeResult {
+    Good,
+    Backwards,
+    /// Looks like a stroke that comes later, with its index
+    OutOfOrder(usize),
+    Off,
+}
+
+impl StrokeResult {
+    fn message(&self) -> String {
+        match self {
+            Self::Good => String::from("Good"),
+            Self::Backwards => String::from("Right place, but it goes the other way"),
+            Self::OutOfOrder(index) => format!("That's stroke {}, it comes later", index + 1),
+            Self::Off => String::from("That doesn't look like the next stroke"),
+        }
+    }
+}
+
+/// Checks a stroke against the one expected at `index`, the backend has the
+/// points of the kanji strokes
+async fn grade_stroke(kanji: String, index: usize, drawn: Vec<Point>) -> StrokeResult {
+    #[derive(Serialize)]
+    struct T {
+        kanji: String,
+        index: usize,
+        drawn: Vec<Point>,
+    }
+    invokers(
+        "grade_stroke",
+        T {
+            kanji,
+            index,
+            drawn,
+        },
+    )
+    .await
+    .unwrap_or(StrokeResult::Off)
+}
+
+/// Pointer position in the box of the paths. Measured from the svg itself,
+/// the offset is from whichever path or polyline is under the pointer
+fn pad_point(reff: NodeRef<svg::Svg>, ev: &ev::PointerEvent) -> Option<Point> {
+    let svg = reff.get_untracked()?;
+    let rect = svg.get_bounding_client_rect();
+    if rect.width() == 0.0 {
+        return None;
+    }
+    let scale = STROKE_BOX as f64 / rect.width();
+    Some((
+        (ev.client_x() as f64 - rect.left()) * scale,
+        (ev.client_y() as f64 - rect.top()) * scale,
+    ))
+}
+
+fn polyline_points(points: &[Point]) -> String {
+    points
+        .iter()
+        .map(|(x, y)| format!("{x},{y}"))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Drawing surface for one kanji, each stroke is checked as soon as it is drawn.
+/// `on_done` gets how many strokes were missed
+#[component]
+pub fn WritingPad(
+    kanji: String,
+    paths: Vec<String>,
+    #[prop(into)] on_done: Callback<usize>,
+) -> impl IntoView {
+    let count = paths.len();
+    let reff: NodeRef<svg::Svg> = create_node_ref();
+    // Strokes done so far
+    let step = create_rw_signal(0usize);
+    let current = create_rw_signal(Vec::<Point>::new());
+    let drawing = create_rw_signal(false);
+    // Waiting for the backend to check a stroke, the next one has to wait
+    let grading = create_rw_signal(false);
+    let misses = create_rw_signal(0usize);
+    let stroke_misses = create_rw_signal(0usize);
+    let feedback = create_rw_signal(None::<StrokeResult>);
+    let strokes = paths
+        .iter()
+        .enumerate()
+        .map(|(index, path)| {
+            let class = move || match index.cmp(&step.get()) {
+                std::cmp::Ordering::Less => "drawn",
+                std::cmp::Ordering::Equal if stroke_misses.get() >= MISSES_BEFORE_HINT => "hint",
+                _ => "hidden",
+            };
+            view! {
+                <path d=path.clone() class=class />
+            }
+        })
+        .collect_view();
+    let kanji = store_value(kanji);
+    let finished = move || step.get_untracked() >= count;
+    let next_stroke = move || {
+        stroke_misses.set(0);
+        step.update(|a| *a += 1);
+        if finished() {
+            on_done.call(misses.get_untracked());
+        }
+    };
+    let pointer_down = move |ev: ev::PointerEvent| {
+        if finished() || grading.get_untracked() {
+            return;
+        }
+        ev.prevent_default();
+        if let Some(svg) = reff.get_untracked() {
+            let _ = svg.set_pointer_capture(ev.pointer_id());
+        }
+        drawing.set(true);
+        current.set(pad_point(reff, &ev).into_iter().collect());
+    };
+    let pointer_move = move |ev: ev::PointerEvent| {
+        if !drawing.get_untracked() {
+            return;
+        }
+        if let Some(point) = pad_point(reff, &ev) {
+            current.update(|a| a.push(point));
+        }
+    };
+    let pointer_up = move |_| {
+        if !drawing.get_untracked() {
+            return;
+        }
+        drawing.set(false);
+        let drawn = current.get_untracked();
+        current.set(Vec::new());
+        if drawn.is_empty() {
+            return;
+        }
+        grading.set(true);
+        spawn_local(async move {
+            let result = grade_stroke(kanji.get_value(), step.get_untracked(), drawn).await;
+            grading.set(false);
+            feedback.set(Some(result));
+            if result == StrokeResult::Good {
+                next_stroke();
+            } else {
+                misses.update(|a| *a += 1);
+                stroke_misses.update(|a| *a += 1);
+            }
+        });
+    };
+    // Both count as missing the stroke
+    let show_stroke = move |_| {
+        if finished() || grading.get_untracked() {
+            return;
+        }
+        misses.update(|a| *a += 1);
+        feedback.set(None);
+        next_stroke();
+    };
+    let give_up = move |_| {
+        if finished() || grading.get_untracked() {
+            return;
+        }
+        misses.update(|a| *a += count - step.get_untracked());
+        step.set(count);
+        feedback.set(None);
+        on_done.call(misses.get_untracked());
+    };
+    view! {
+        <div class="writing">
+            <svg
+                ref=reff
+                xmlns="http://www.w3.org/2000/svg"
+                viewBox=format!("0 0 {STROKE_BOX} {STROKE_BOX}")
+                on:pointerdown=pointer_down
+                on:pointermove=pointer_move
+                on:pointerup=pointer_up
+                on:pointercancel=pointer_up
+            >
+                {strokes}
+                <polyline points=move || current.with(|a| polyline_points(a)) />
+            </svg>
+            <span class="feedback" class:good=move || feedback.get() == Some(StrokeResult::Good)>
+                {move || feedback.get().map(|a| a.message())}
+            </span>
+            <div>
+                <span>
+                    {move || step.get()} / {count}
+                </span>
+                <button type="button" on:click=show_stroke>
+                    Show the stroke
+                </button>
+                <button type="button" on:click=give_up>
+                    "I don't know"
+                </button>
+            </div>
+        </div>
+    }
+}
+
+/// Free drawing surface, `on_change` gets every stroke drawn so far
+#[component]
+pub fn SketchPad(#[prop(into)] on_change: Callback<Vec<Vec<Point>>>) -> impl IntoView {
+    let reff: NodeRef<svg::Svg> = create_node_ref();
+    let strokes = create_rw_signal(Vec::<Vec<Point>>::new());
+    let current = create_rw_signal(Vec::<Point>::new());
+    let drawing = create_rw_signal(false);
+    let set_strokes = move |new: Vec<Vec<Point>>| {
+        strokes.set(new.clone());
+        on_change.call(new);
+    };
+    let pointer_down = move |ev: ev::PointerEvent| {
+        ev.prevent_default();
+        if let Some(svg) = reff.get_untracked() {
+            let _ = svg.set_pointer_capture(ev.pointer_id());
+        }
+        drawing.set(true);
+        current.set(pad_point(reff, &ev).into_iter().collect());
+    };
+    let pointer_move = move |ev: ev::PointerEvent| {
+        if !drawing.get_untracked() {
+            return;
+        }
+        if let Some(point) = pad_point(reff, &ev) {
+            current.update(|a| a.push(point));
+        }
+    };
+    let pointer_up = move |_| {
+        if !drawing.get_untracked() {
+            return;
+        }
+        drawing.set(false);
+        let drawn = current.get_untracked();
+        current.set(Vec::new());
+        if !drawn.is_empty() {
+            let mut all = strokes.get_untracked();
+            all.push(drawn);
+            set_strokes(all);
+        }
+    };
+    view! {
+        <div class="sketch">
+            <svg
+                ref=reff
+                xmlns="http://www.w3.org/2000/svg"
+                viewBox=format!("0 0 {STROKE_BOX} {STROKE_BOX}")
+                on:pointerdown=pointer_down
+                on:pointermove=pointer_move
+                on:pointerup=pointer_up
+                on:pointercancel=pointer_up
+            >
+                {move || strokes.with(|a| a.iter().map(|stroke| view! {
+                    <polyline points=polyline_points(stroke) />
+                }).collect_view())}
+                <polyline class="current" points=move || current.with(|a| polyline_points(a)) />
+            </svg>
+            <div>
+                <button type="button" on:click=move |_| {
+                    let mut all = strokes.get_untracked();
+                    all.pop();
+                    set_strokes(all);
+                }>
+                    Undo
+                </button>
+                <button type="button" on:click=move |_| set_strokes(Vec::new())>
+                    Clear
+                </button>
+            </div>
+        </div>
+    }
+}